@@ -0,0 +1,61 @@
+import chai from 'chai';
+import {
+  isJsonContentType,
+  isXmlContentType,
+  mediaType,
+} from './contentType';
+
+const { expect } = chai;
+
+describe('Content types', () => {
+  describe('#mediaType', () => {
+    it('removes any parameters and normalises the case', () => {
+      expect(mediaType('Application/HAL+JSON; charset=UTF-8')).to.eq(
+        'application/hal+json'
+      );
+    });
+  });
+
+  describe('#isJsonContentType', () => {
+    [
+      'application/json',
+      'text/json',
+      'application/hal+json',
+      'application/vnd.api+json',
+      'application/problem+json; charset=utf-8',
+    ].forEach((contentType) => {
+      it(`is true for ${contentType}`, () => {
+        expect(isJsonContentType(contentType)).to.eq(true);
+      });
+    });
+
+    ['text/plain', 'application/jsonp', 'application/json+xml'].forEach(
+      (contentType) => {
+        it(`is false for ${contentType}`, () => {
+          expect(isJsonContentType(contentType)).to.eq(false);
+        });
+      }
+    );
+  });
+
+  describe('#isXmlContentType', () => {
+    [
+      'application/xml',
+      'text/xml',
+      'application/atom+xml',
+      'application/soap+xml; charset=utf-8',
+    ].forEach((contentType) => {
+      it(`is true for ${contentType}`, () => {
+        expect(isXmlContentType(contentType)).to.eq(true);
+      });
+    });
+
+    ['application/json', 'application/xhtml', 'application/xml+json'].forEach(
+      (contentType) => {
+        it(`is false for ${contentType}`, () => {
+          expect(isXmlContentType(contentType)).to.eq(false);
+        });
+      }
+    );
+  });
+});
@@ -0,0 +1,46 @@
+/**
+ * Content type helpers.
+ * @module contentType
+ * @private
+ */
+
+// Media types that use a structured syntax suffix (RFC 6839), e.g.
+// application/vnd.api+json or application/atom+xml
+const suffixedMediaType = (suffix: string): RegExp =>
+  new RegExp(`^[a-z0-9!#$&^_.-]+/[a-z0-9!#$&^_.+-]+\\+${suffix}$`);
+
+const JSON_SUFFIX = suffixedMediaType('json');
+const XML_SUFFIX = suffixedMediaType('xml');
+
+/**
+ * Returns the media type of a content type, without any parameters (e.g.
+ * `application/hal+json` for `application/hal+json; charset=utf-8`)
+ */
+export const mediaType = (contentType: string): string =>
+  contentType.split(';')[0].trim().toLowerCase();
+
+/**
+ * True if the content type is JSON, including media types with the `+json`
+ * suffix such as `application/hal+json` or `application/problem+json`
+ */
+export const isJsonContentType = (contentType: string): boolean => {
+  const type = mediaType(contentType);
+
+  return (
+    type === 'application/json' ||
+    type === 'text/json' ||
+    JSON_SUFFIX.test(type)
+  );
+};
+
+/**
+ * True if the content type is XML, including media types with the `+xml`
+ * suffix such as `application/atom+xml` or `application/soap+xml`
+ */
+export const isXmlContentType = (contentType: string): boolean => {
+  const type = mediaType(contentType);
+
+  return (
+    type === 'application/xml' || type === 'text/xml' || XML_SUFFIX.test(type)
+  );
+};
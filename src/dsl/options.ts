@@ -5,6 +5,7 @@
 import { VerifierOptions as PactCoreVerifierOptions } from '@pact-foundation/pact-core';
 import { PactfileWriteMode } from './mockService';
 import { MessageProviders, MessageStateHandlers } from './message';
+import { DiffOptions } from '../v3/diff';
 
 export type LogLevel = 'trace' | 'debug' | 'info' | 'warn' | 'error';
 
@@ -51,6 +52,9 @@ export interface PactOptions {
   // Control how the Pact files are written
   // (defaults to 'overwrite')
   pactfileWriteMode?: PactfileWriteMode;
+
+  // How body differences are shown when the mock server reports a mismatch
+  diff?: DiffOptions;
 }
 
 export interface MandatoryPactOptions {
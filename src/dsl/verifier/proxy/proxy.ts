@@ -10,6 +10,7 @@ import { registerAfterHook, registerBeforeHook } from './hooks';
 import { createRequestTracer, createResponseTracer } from './tracer';
 import { createProxyMessageHandler } from './messages';
 import { toServerOptions } from './proxyRequest';
+import { createRequestRecorder, VerificationRecorder } from './recorder';
 
 // Listens for the server start event
 export const waitForServerReady = (server: http.Server): Promise<http.Server> =>
@@ -24,7 +25,8 @@ export const waitForServerReady = (server: http.Server): Promise<http.Server> =>
 export const createProxy = (
   config: ProxyOptions,
   stateSetupPath: string,
-  messageTransportPath: string
+  messageTransportPath: string,
+  recorder: VerificationRecorder = new VerificationRecorder()
 ): http.Server => {
   const app = express();
   const proxy = new HttpProxy();
@@ -59,14 +61,18 @@ export const createProxy = (
   }
 
   // Setup provider state handler
-  app.post(stateSetupPath, createProxyStateHandler(config));
+  app.post(stateSetupPath, createProxyStateHandler(config, recorder));
 
   // Register message handler and transport
   // TODO: ensure proxy does not interfere with this
-  app.post(messageTransportPath, createProxyMessageHandler(config));
+  app.post(
+    messageTransportPath,
+    createRequestRecorder(recorder),
+    createProxyMessageHandler(config)
+  );
 
   // Proxy server will respond to Verifier process
-  app.all('/*', (req, res) => {
+  app.all('/*', createRequestRecorder(recorder), (req, res) => {
     logger.debug(`Proxying ${req.method}: ${req.path}`);
 
     proxy.web(req, res, toServerOptions(config, req));
@@ -0,0 +1,110 @@
+import chai from 'chai';
+import { VerificationRecorder } from './recorder';
+
+const { expect } = chai;
+
+describe('VerificationRecorder', () => {
+  const started = new Date();
+  let recorder: VerificationRecorder;
+
+  beforeEach(() => {
+    recorder = new VerificationRecorder();
+
+    const now = Date.now();
+    recorder.stateChange(
+      { state: 'an order exists', action: 'setup', params: {} },
+      now
+    );
+    recorder.request({ method: 'GET', path: '/orders/1' }, now, 200);
+    recorder.stateChange(
+      { state: 'an order exists', action: 'teardown', params: {} },
+      now
+    );
+    recorder.stateChange(
+      { state: 'a user exists', action: 'setup', params: {} },
+      now,
+      new Error('no database')
+    );
+    recorder.request({ method: 'GET', path: '/users/1' }, now, 500);
+    recorder.request({ method: 'GET', path: '/health' }, now, 200);
+  });
+
+  it('groups the state changes and requests into interactions', () => {
+    expect(
+      recorder.interactions.map((i) => [
+        i.request,
+        i.stateChanges.map((s) => `${s.action} ${s.state}`),
+      ])
+    ).to.deep.eq([
+      [
+        { method: 'GET', path: '/orders/1' },
+        ['setup an order exists', 'teardown an order exists'],
+      ],
+      [{ method: 'GET', path: '/users/1' }, ['setup a user exists']],
+      [{ method: 'GET', path: '/health' }, []],
+    ]);
+  });
+
+  describe('#results', () => {
+    it('includes the state handlers invoked for each interaction', () => {
+      const [first, second] = recorder.results();
+
+      expect(first).to.include({ status: 'passed', responseStatus: 200 });
+      expect(first.providerStates).to.deep.eq(['an order exists']);
+      expect(
+        first.stateChanges.map((s) => [s.state, s.action, s.error])
+      ).to.deep.eq([
+        ['an order exists', 'setup', undefined],
+        ['an order exists', 'teardown', undefined],
+      ]);
+      expect(first.stateChanges[0].durationMs).to.be.a('number');
+      expect(second.stateChanges[0].error).to.eq('no database');
+    });
+
+    it('has an unknown outcome for interactions when the verification fails', () => {
+      expect(
+        recorder.results(new Error('Verification failed')).map((i) => i.status)
+      ).to.deep.eq(['unknown', 'failed', 'unknown']);
+    });
+  });
+
+  describe('#toTestSuite', () => {
+    it('reports each interaction as a test case', () => {
+      const suite = recorder.toTestSuite('Verification of b', started);
+
+      expect(suite.role).to.eq('provider');
+      expect(
+        suite.testCases.map((t) => [t.name, t.status, t.failure])
+      ).to.deep.eq([
+        ['GET /orders/1 given an order exists', 'passed', undefined],
+        [
+          'GET /users/1 given a user exists',
+          'failed',
+          "State handler for 'a user exists' failed: no database",
+        ],
+        ['GET /health', 'passed', undefined],
+      ]);
+    });
+
+    it('reports the verification failure, as the outcome of the other interactions is unknown', () => {
+      const suite = recorder.toTestSuite(
+        'Verification of b',
+        started,
+        new Error('Verification failed')
+      );
+
+      expect(
+        suite.testCases.map((t) => [t.name, t.status, t.failure])
+      ).to.deep.eq([
+        ['GET /orders/1 given an order exists', 'unknown', undefined],
+        [
+          'GET /users/1 given a user exists',
+          'failed',
+          "State handler for 'a user exists' failed: no database",
+        ],
+        ['GET /health', 'unknown', undefined],
+        ['Provider verification', 'failed', 'Verification failed'],
+      ]);
+    });
+  });
+});
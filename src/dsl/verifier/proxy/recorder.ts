@@ -0,0 +1,220 @@
+import express from 'express';
+import { ProviderState } from './types';
+import {
+  TestCaseResult,
+  TestCaseStatus,
+  TestSuiteResult,
+} from '../../../reporters/types';
+
+/**
+ * A call to a provider state handler made by the verifier
+ */
+export interface StateChange {
+  state: string;
+  action: ProviderState['action'];
+  durationMs: number;
+  error?: string;
+}
+
+/**
+ * The outcome of verifying an interaction, as far as Pact JS can tell
+ */
+export interface InteractionVerificationResult {
+  /** The message description, for message interactions */
+  description?: string;
+  /** The request sent to the provider */
+  request?: { method: string; path: string };
+  /** The status the provider responded with */
+  responseStatus?: number;
+  providerStates: string[];
+  /** The state handlers invoked for the interaction, and their timings */
+  stateChanges: StateChange[];
+  status: TestCaseStatus;
+  /** Time taken in milliseconds, including the state changes */
+  durationMs: number;
+  failure?: string;
+}
+
+/**
+ * An interaction as seen by the proxy: the state changes made for it, and
+ * the request sent to the provider
+ */
+export interface ObservedInteraction {
+  /** The message description, for message interactions */
+  description?: string;
+  stateChanges: StateChange[];
+  request?: { method: string; path: string };
+  /** Status the provider responded with */
+  status?: number;
+  startedAt: number;
+  finishedAt: number;
+  error?: string;
+}
+
+/**
+ * Records the state changes and requests the verifier makes through the
+ * proxy. The verifier sets up the states for an interaction, sends its
+ * request and then tears the states down, so a state setup after a request
+ * starts the next interaction.
+ */
+export class VerificationRecorder {
+  public readonly interactions: ObservedInteraction[] = [];
+
+  private current?: ObservedInteraction;
+
+  private interactionFor(
+    startsInteraction: boolean,
+    startedAt: number
+  ): ObservedInteraction {
+    if (!this.current || startsInteraction) {
+      this.current = { stateChanges: [], startedAt, finishedAt: startedAt };
+      this.interactions.push(this.current);
+    }
+
+    return this.current;
+  }
+
+  public stateChange(
+    state: ProviderState,
+    startedAt: number,
+    error?: Error
+  ): void {
+    const finishedAt = Date.now();
+    const interaction = this.interactionFor(
+      state.action === 'setup' && this.current?.request !== undefined,
+      startedAt
+    );
+
+    interaction.stateChanges.push({
+      state: state.state,
+      action: state.action,
+      durationMs: finishedAt - startedAt,
+      error: error?.message,
+    });
+    interaction.finishedAt = finishedAt;
+    if (error && !interaction.error) {
+      interaction.error = `State handler for '${state.state}' failed: ${error.message}`;
+    }
+  }
+
+  public request(
+    request: { method: string; path: string; description?: string },
+    startedAt: number,
+    status: number,
+    error?: Error
+  ): void {
+    const { description, ...rest } = request;
+    const interaction = this.interactionFor(
+      this.current?.request !== undefined,
+      startedAt
+    );
+
+    if (description) {
+      interaction.description = description;
+    }
+    interaction.request = rest;
+    interaction.status = status;
+    interaction.finishedAt = Date.now();
+    if (error && !interaction.error) {
+      interaction.error = `Request failed: ${error.message}`;
+    }
+  }
+
+  /**
+   * The outcome of each observed interaction. The verifier only reports
+   * whether the whole verification passed, so when it fails, any interaction
+   * that did not fail in the proxy has an unknown outcome
+   */
+  public results(error?: Error): InteractionVerificationResult[] {
+    return this.interactions.map((i) => {
+      let status: TestCaseStatus = 'passed';
+      if (i.error) {
+        status = 'failed';
+      } else if (error) {
+        status = 'unknown';
+      }
+
+      return {
+        description: i.description,
+        request: i.request,
+        responseStatus: i.status,
+        providerStates: i.stateChanges
+          .filter((s) => s.action === 'setup' && s.state)
+          .map((s) => s.state),
+        stateChanges: i.stateChanges,
+        status,
+        durationMs: i.finishedAt - i.startedAt,
+        failure: i.error,
+      };
+    });
+  }
+
+  /**
+   * Reports each observed interaction as a test case. When the verification
+   * fails, the failure is reported as a test case of its own.
+   */
+  public toTestSuite(
+    name: string,
+    started: Date,
+    error?: Error
+  ): TestSuiteResult {
+    const testCases = this.results(error).map((i): TestCaseResult => {
+      const request = i.request
+        ? `${i.request.method} ${i.request.path}`
+        : 'State change';
+      const given =
+        i.providerStates.length > 0
+          ? ` given ${i.providerStates.join(', ')}`
+          : '';
+
+      return {
+        name: i.description ?? `${request}${given}`,
+        providerStates: i.providerStates,
+        request: i.request,
+        durationMs: i.durationMs,
+        status: i.status,
+        mismatches: [],
+        failure: i.failure,
+      };
+    });
+
+    if (error) {
+      testCases.push({
+        name: 'Provider verification',
+        providerStates: [],
+        durationMs: 0,
+        status: 'failed',
+        mismatches: [],
+        failure: error.message,
+      });
+    }
+
+    return {
+      name,
+      role: 'provider',
+      timestamp: started.toISOString(),
+      durationMs: Date.now() - started.getTime(),
+      testCases,
+    };
+  }
+}
+
+// Records each request the verifier sends through the proxy once it has
+// been responded to
+export const createRequestRecorder =
+  (recorder: VerificationRecorder): express.RequestHandler =>
+  (req, res, next) => {
+    const startedAt = Date.now();
+    res.on('finish', () =>
+      recorder.request(
+        {
+          method: req.method,
+          path: req.path,
+          description: req.body?.description,
+        },
+        startedAt,
+        res.statusCode
+      )
+    );
+    next();
+  };
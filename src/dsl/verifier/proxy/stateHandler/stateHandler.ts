@@ -2,13 +2,21 @@ import express from 'express';
 
 import { ProxyOptions, ProviderState } from '../types';
 import { setupStates } from './setupStates';
+import { VerificationRecorder } from '../recorder';
 
 export const createProxyStateHandler =
-  (config: ProxyOptions) =>
+  (config: ProxyOptions, recorder?: VerificationRecorder) =>
   (req: express.Request, res: express.Response): Promise<express.Response> => {
     const message: ProviderState = req.body;
+    const startedAt = Date.now();
 
     return Promise.resolve(setupStates(message, config))
-      .then((data) => res.json(data))
-      .catch((e) => res.status(500).send(e));
+      .then((data) => {
+        recorder?.stateChange(message, startedAt);
+        return res.json(data);
+      })
+      .catch((e) => {
+        recorder?.stateChange(message, startedAt, e);
+        return res.status(500).send(e);
+      });
   };
@@ -2,6 +2,7 @@ import { VerifierOptions as PactCoreVerifierOptions } from '@pact-foundation/pac
 import { MessageProviderOptions } from '../options';
 
 import { ProxyOptions } from './proxy/types';
+import { Reporter } from '../../reporters/types';
 
 type ExcludedPactNodeVerifierKeys = Exclude<
   keyof PactCoreVerifierOptions,
@@ -13,6 +14,12 @@ export type PactNodeVerificationExcludedOptions = Pick<
   ExcludedPactNodeVerifierKeys
 >;
 
+export interface VerificationReportingOptions {
+  /** Reporters to send the result of each interaction to */
+  reporters?: Reporter[];
+}
+
 export type VerifierOptions = PactNodeVerificationExcludedOptions &
   ProxyOptions &
+  VerificationReportingOptions &
   Partial<MessageProviderOptions>;
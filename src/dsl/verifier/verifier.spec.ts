@@ -8,6 +8,7 @@ import * as proxy from './proxy/proxy';
 import logger from '../../common/logger';
 
 import { VerifierOptions } from './types';
+import { TestSuiteResult } from '../../reporters';
 
 import { Verifier } from './verifier';
 
@@ -149,6 +150,45 @@ describe('Verifier', () => {
           });
         });
       });
+
+      context('and the verification completes', () => {
+        it('resolves with the output of the verifier', async () => {
+          sinon
+            .stub(v, 'runProviderVerification' as any)
+            .returns(() => Promise.resolve('finished: 0'));
+
+          expect(await v.verifyProvider()).to.eq('finished: 0');
+        });
+      });
+
+      context('and reporters are configured', () => {
+        it('reports the result of the verification', async () => {
+          const suites: TestSuiteResult[] = [];
+          v = new Verifier({
+            ...opts,
+            provider: 'b',
+            reporters: [{ report: (suite) => suites.push(suite) }],
+          });
+          sinon
+            .stub(v, 'runProviderVerification' as any)
+            .returns(() => Promise.reject(new Error('error')));
+
+          await expect(v.verifyProvider()).to.eventually.be.rejected;
+
+          expect(suites).to.have.lengthOf(1);
+          expect(suites[0].name).to.eq('Verification of b');
+          expect(suites[0].testCases).to.deep.eq([
+            {
+              name: 'Provider verification',
+              providerStates: [],
+              durationMs: 0,
+              status: 'failed',
+              mismatches: [],
+              failure: 'error',
+            },
+          ]);
+        });
+      });
     });
   });
 });
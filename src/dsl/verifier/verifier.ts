@@ -15,7 +15,9 @@ import logger, { setLogLevel } from '../../common/logger';
 import ConfigurationError from '../../errors/configurationError';
 import { localAddresses } from '../../common/net';
 import { createProxy, waitForServerReady } from './proxy';
+import { VerificationRecorder } from './proxy/recorder';
 import { VerifierOptions } from './types';
+import { report } from '../../reporters';
 
 export class Verifier {
   private address = 'http://127.0.0.1';
@@ -76,8 +78,6 @@ export class Verifier {
 
   /**
    * Verify a HTTP Provider
-   *
-   * @param config
    */
   public verifyProvider(): Promise<string> {
     logger.info('Verifying provider');
@@ -89,10 +89,13 @@ export class Verifier {
     }
 
     // Start the verification CLI proxy server
+    const recorder = new VerificationRecorder();
+    const started = new Date();
     const server = createProxy(
       this.config,
       this.stateSetupPath,
-      this.messageTransportPath
+      this.messageTransportPath,
+      recorder
     );
     logger.trace(`proxy created, waiting for startup`);
 
@@ -105,25 +108,42 @@ export class Verifier {
         return passOn;
       })
       .then(this.runProviderVerification())
-      .then((result) => {
+      .then(async (result) => {
         logger.trace('Verification completed, closing server');
         server.close();
+        await this.reportResults(recorder, started);
         return result;
       })
-      .catch((e) => {
+      .catch(async (e) => {
         logger.trace(`Verification failed(${e.message}), closing server`);
         server.close();
+        await this.reportResults(recorder, started, e);
         throw e;
       });
   }
 
+  private reportResults(
+    recorder: VerificationRecorder,
+    started: Date,
+    error?: Error
+  ): Promise<void> {
+    return report(
+      this.config.reporters,
+      recorder.toTestSuite(
+        `Verification of ${this.config.provider ?? 'provider'}`,
+        started,
+        error
+      )
+    );
+  }
+
   // Run the Verification CLI process
   private runProviderVerification() {
     return (server: http.Server) => {
       const { port } = server.address() as AddressInfo;
       const opts: PactCoreVerifierOptions = {
         providerStatesSetupUrl: `${this.address}:${port}${this.stateSetupPath}`,
-        ...omit(this.config, 'handlers'),
+        ...omit(this.config, 'handlers', 'reporters'),
         providerBaseUrl: `${this.address}:${port}`,
         transports: this.config.transports?.concat([
           {
@@ -0,0 +1,134 @@
+import VerificationError from './verificationError';
+
+export type ContractMismatchKind =
+  | 'request-mismatch'
+  | 'request-not-found'
+  | 'missing-request'
+  | 'plugin-mismatch';
+
+/**
+ * A single mismatch between the contract and what the mock server received
+ */
+export interface ContractMismatch {
+  /**
+   * Whether a request was incorrect, not expected, expected but not received,
+   * or failed to match a plugin's content
+   */
+  kind: ContractMismatchKind;
+  /** The request the mismatch relates to */
+  request?: { method: string; path: string };
+  /** The type of mismatch reported by the core, e.g. BodyMismatch */
+  type?: string;
+  /**
+   * Where the mismatch occurred, e.g. the path in the body, or the name of
+   * the header or query parameter
+   */
+  path?: string;
+  expected?: unknown;
+  actual?: unknown;
+  /** The type of matching rule that failed, when the core reports it */
+  matcherType?: string;
+  /** Description of the mismatch */
+  mismatch: string;
+  diff?: string;
+}
+
+/**
+ * A short description of the mismatches, e.g. for the failure of a test case
+ */
+export const mismatchSummary = (mismatches: ContractMismatch[]): string =>
+  `${mismatches.length} ${
+    mismatches.length === 1 ? 'mismatch' : 'mismatches'
+  } with the contract`;
+
+const display = (value: unknown): string =>
+  typeof value === 'string' ? value : JSON.stringify(value);
+
+const requestLine = (m: ContractMismatch): string =>
+  m.request ? `${m.request.method} ${m.request.path}` : 'Plugin content';
+
+const describeKind = (kind: ContractMismatchKind): string => {
+  switch (kind) {
+    case 'request-mismatch':
+      return 'The following request was incorrect';
+    case 'request-not-found':
+      return 'The following request was not expected';
+    case 'missing-request':
+      return 'The following request was expected but not received';
+    default:
+      return 'The following content did not match';
+  }
+};
+
+const printMismatch = (m: ContractMismatch): string[] => {
+  const location = [m.path, m.type && `(${m.type})`].filter(Boolean).join(' ');
+  const lines = [location ? `${location}: ${m.mismatch}` : m.mismatch];
+
+  if (m.kind === 'request-mismatch' || m.kind === 'plugin-mismatch') {
+    if (m.expected !== undefined) {
+      lines.push(`  Expected: ${display(m.expected)}`);
+    }
+    if (m.actual !== undefined) {
+      lines.push(`  Actual:   ${display(m.actual)}`);
+    }
+  }
+  if (m.diff) {
+    lines.push('  Diff:', ...m.diff.split('\n').map((l) => `    ${l}`));
+  }
+
+  return lines;
+};
+
+/**
+ * Thrown when the mock server did not receive the requests described by the
+ * contract. The message has the details of the mismatches as rendered for the
+ * test output, and the individual mismatches are available in `mismatches`,
+ * for reporters that want to render them rather than parse the message.
+ */
+export default class ContractMismatchError extends VerificationError {
+  public readonly mismatches: ContractMismatch[];
+
+  constructor(message: string, mismatches: ContractMismatch[]) {
+    super(message);
+    // Restore the prototype chain, which is lost when extending Error in ES5
+    Object.setPrototypeOf(this, ContractMismatchError.prototype);
+    this.name = 'ContractMismatchError';
+    this.mismatches = mismatches;
+  }
+
+  public toJSON(): {
+    name: string;
+    message: string;
+    mismatches: ContractMismatch[];
+  } {
+    return {
+      name: this.name,
+      message: this.message,
+      mismatches: this.mismatches,
+    };
+  }
+
+  /**
+   * Renders the mismatches grouped by request. The message is replaced with a
+   * summary, as it already describes the mismatches
+   */
+  public toString(): string {
+    const output = [`${this.name}: ${mismatchSummary(this.mismatches)}`];
+    let group = '';
+    let count = 0;
+
+    this.mismatches.forEach((m) => {
+      const heading = `${describeKind(m.kind)}: ${requestLine(m)}`;
+      if (heading !== group) {
+        group = heading;
+        count += 1;
+        output.push('', `  ${count}) ${heading}`);
+      }
+      if (m.kind === 'request-mismatch' || m.kind === 'plugin-mismatch') {
+        printMismatch(m).forEach((l) => output.push(`     ${l}`));
+      }
+    });
+
+    return output.join('\n');
+  }
+}
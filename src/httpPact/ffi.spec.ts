@@ -4,6 +4,7 @@ import chaiAsPromised from 'chai-as-promised';
 import sinon from 'sinon';
 import sinonChai from 'sinon-chai';
 import { contentTypeFromHeaders, setQuery } from './ffi';
+import { term } from '../dsl/matchers';
 
 chai.use(sinonChai);
 chai.use(chaiAsPromised);
@@ -23,6 +24,20 @@ describe('Pact FFI', () => {
       });
     });
 
+    describe('when the header is a matcher', () => {
+      it('uses the example value of the matcher', () => {
+        const headers = {
+          'Content-Type': term({
+            generate: 'application/hal+json',
+            matcher: '^application\\/(.+\\+)?json',
+          }),
+        };
+        expect(contentTypeFromHeaders(headers, 'application/json')).to.eq(
+          'application/hal+json'
+        );
+      });
+    });
+
     describe(`when the no content-type header is set`, () => {
       it('uses a default', () => {
         expect(contentTypeFromHeaders({}, 'application/json')).to.eq(
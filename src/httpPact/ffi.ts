@@ -7,7 +7,7 @@ import {
   Headers,
   Query,
 } from '../dsl/interaction';
-import { Matcher, matcherValueOrString } from '../dsl/matchers';
+import { isMatcher, Matcher, matcherValueOrString } from '../dsl/matchers';
 import logger from '../common/logger';
 
 enum InteractionPart {
@@ -18,14 +18,16 @@ enum InteractionPart {
 const CONTENT_TYPE_HEADER = 'content-type';
 const CONTENT_TYPE_JSON = 'application/json';
 
+// A matcher on the header (e.g. a term for any JSON media type) uses its
+// example value as the content type
 export const contentTypeFromHeaders = (
   headers: Headers | undefined,
   defaultContentType: string
 ): string => {
-  let contentType: string | Matcher<string> = defaultContentType;
+  let contentType = defaultContentType;
   forEachObjIndexed((v, k) => {
     if (`${k}`.toLowerCase() === CONTENT_TYPE_HEADER) {
-      contentType = matcherValueOrString(v);
+      contentType = isMatcher(v) ? `${v.getValue()}` : matcherValueOrString(v);
     }
   }, headers || {});
 
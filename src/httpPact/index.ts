@@ -17,11 +17,11 @@ import {
 import { freePort, isPortAvailable } from '../common/net';
 import logger, { setLogLevel } from '../common/logger';
 import { LogLevel, PactOptions, PactOptionsComplete } from '../dsl/options';
-import VerificationError from '../errors/verificationError';
+import ContractMismatchError from '../errors/contractMismatchError';
 import ConfigurationError from '../errors/configurationError';
 import { SpecificationVersion } from '../v3';
 import { version as pactPackageVersion } from '../../package.json';
-import { generateMockServerError } from '../v3/display';
+import { contractMismatches, generateMockServerError } from '../v3/display';
 import { numberToSpec } from '../common/spec';
 import { MockService } from '../dsl/mockService';
 import { setRequestDetails, setResponseDetails } from './ffi';
@@ -203,7 +203,11 @@ export class Pact {
     // Feature flag: allow missing requests on the mock service
     if (!success) {
       let error = 'Test failed for the following reasons:';
-      error += `\n\n  ${generateMockServerError(matchingResults, '\t')}`;
+      error += `\n\n  ${generateMockServerError(
+        matchingResults,
+        '\t',
+        this.opts.diff
+      )}`;
 
       /* eslint-disable no-console */
       console.error('');
@@ -212,8 +216,9 @@ export class Pact {
       /* eslint-enable */
 
       this.reset();
-      throw new VerificationError(
-        'Pact verification failed - expected interactions did not match actual.'
+      throw new ContractMismatchError(
+        `Pact verification failed - expected interactions did not match actual.\n\n${error}`,
+        contractMismatches(matchingResults, this.opts.diff)
       );
     }
 
@@ -87,6 +87,34 @@ export * from './v3';
 
 export * from './v4';
 
+/**
+ * Exposes {@link ContractMismatchError}
+ * @memberof Pact
+ * @static
+ */
+export {
+  default as ContractMismatchError,
+  ContractMismatch,
+  ContractMismatchKind,
+} from './errors/contractMismatchError';
+
+/**
+ * Exposes {@link Reporter}
+ * @memberof Pact
+ * @static
+ */
+export {
+  Reporter,
+  TestCaseResult,
+  TestCaseStatus,
+  TestSuiteResult,
+  JsonReport,
+  jsonReporter,
+  junitReporter,
+  toJsonReport,
+  toJUnitXml,
+} from './reporters';
+
 /**
  * Exposes {@link PactOptions}
  * @memberof Pact
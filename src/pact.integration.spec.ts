@@ -9,6 +9,10 @@ process.env.ENABLE_FEATURE_V4 = 'true';
 
 // eslint-disable-next-line import/first
 import { PactV4 } from './v4';
+// eslint-disable-next-line import/first
+import { MatchersV3 } from './v3';
+// eslint-disable-next-line import/first
+import ContractMismatchError from './errors/contractMismatchError';
 
 const { expect } = chai;
 
@@ -63,6 +67,67 @@ describe('V4 Pact', () => {
             }
           )
         ));
+
+    it('accepts a value that matches all of the matchers of allOf', () =>
+      pact
+        .addInteraction()
+        .uponReceiving('a request to rename a thing')
+        .withRequest('PUT', '/things/1', (builder) => {
+          builder.jsonBody({
+            name: MatchersV3.allOf(
+              MatchersV3.regex('^\\w+$', 'abc'),
+              MatchersV3.notEmpty('abc')
+            ),
+          });
+        })
+        .willRespondWith(204)
+        .executeTest(async (server) => {
+          const res = await axios.put(`${server.url}/things/1`, {
+            name: 'xyz',
+          });
+
+          expect(res.status).to.eq(204);
+        }));
+  });
+
+  describe('Several interactions in one test', () => {
+    const addInteractions = () =>
+      pact
+        .addInteraction()
+        .uponReceiving('a request for the dogs')
+        .withRequest('GET', '/dogs')
+        .willRespondWith(200, (builder) => {
+          builder.jsonBody([{ name: MatchersV3.string('rover') }]);
+        })
+        .addInteraction()
+        .uponReceiving('a request for the cats')
+        .withRequest('GET', '/cats')
+        .willRespondWith(200, (builder) => {
+          builder.jsonBody([{ name: MatchersV3.string('felix') }]);
+        });
+
+    it('serves each of the interactions', () =>
+      addInteractions().executeTest(async (server) => {
+        const dogs = await axios.get(`${server.url}/dogs`);
+        const cats = await axios.get(`${server.url}/cats`);
+
+        expect(dogs.data).to.deep.eq([{ name: 'rover' }]);
+        expect(cats.data).to.deep.eq([{ name: 'felix' }]);
+      }));
+
+    it('fails when one of the interactions is not exercised', async () => {
+      const error = await addInteractions()
+        .executeTest(async (server) => axios.get(`${server.url}/dogs`))
+        .then(
+          () => expect.fail('the test should have failed'),
+          (e: ContractMismatchError) => e
+        );
+
+      expect(error).to.be.instanceOf(ContractMismatchError);
+      expect(error.mismatches.map((m) => [m.kind, m.request])).to.deep.eq([
+        ['missing-request', { method: 'GET', path: '/cats' }],
+      ]);
+    });
   });
 
   describe('Plugin test', () => {
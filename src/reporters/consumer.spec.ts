@@ -0,0 +1,143 @@
+import chai from 'chai';
+import { ContractMismatch } from '../errors/contractMismatchError';
+import { integer, regex } from '../v3/matchers';
+import {
+  consumerTestSuite,
+  RecordedInteraction,
+  recordedRequest,
+} from './consumer';
+
+const { expect } = chai;
+
+describe('#consumerTestSuite', () => {
+  const interactions: RecordedInteraction[] = [
+    {
+      description: 'a request for an order',
+      providerStates: ['an order exists'],
+      request: { method: 'GET', path: '/orders/1' },
+    },
+    {
+      description: 'a request to create an order',
+      providerStates: [],
+      request: { method: 'POST', path: '/orders' },
+    },
+  ];
+  const mismatch: ContractMismatch = {
+    kind: 'request-mismatch',
+    request: { method: 'POST', path: '/orders' },
+    type: 'BodyMismatch',
+    path: '$.id',
+    mismatch: 'Expected 2 to be equal to 1',
+  };
+  const started = new Date();
+
+  it('reports each interaction as a test case', () => {
+    const suite = consumerTestSuite(
+      'Pact between a and b',
+      interactions,
+      started,
+      []
+    );
+
+    expect(suite.name).to.eq('Pact between a and b');
+    expect(suite.role).to.eq('consumer');
+    expect(suite.timestamp).to.eq(started.toISOString());
+    expect(suite.testCases.map((t) => [t.name, t.status])).to.deep.eq([
+      ['a request for an order', 'passed'],
+      ['a request to create an order', 'passed'],
+    ]);
+    expect(suite.testCases[0].providerStates).to.deep.eq(['an order exists']);
+  });
+
+  it('attributes mismatches to the interaction with the same request', () => {
+    const suite = consumerTestSuite(
+      'Pact between a and b',
+      interactions,
+      started,
+      [mismatch]
+    );
+
+    expect(suite.testCases[0].status).to.eq('passed');
+    expect(suite.testCases[1].status).to.eq('failed');
+    expect(suite.testCases[1].mismatches).to.deep.eq([mismatch]);
+    expect(suite.testCases[1].failure).to.eq('1 mismatch with the contract');
+  });
+
+  it('attributes mismatches to the interaction whose path matcher matches the request', () => {
+    const suite = consumerTestSuite(
+      'Pact between a and b',
+      [
+        {
+          description: 'a request for an order',
+          providerStates: [],
+          request: recordedRequest({
+            method: 'GET',
+            path: regex('/orders/\\d+', '/orders/1'),
+          }),
+        },
+        ...interactions.slice(1),
+      ],
+      started,
+      [{ ...mismatch, request: { method: 'GET', path: '/orders/42' } }]
+    );
+
+    expect(suite.testCases.map((t) => t.status)).to.deep.eq([
+      'failed',
+      'passed',
+    ]);
+    expect(suite.testCases[0].request).to.deep.eq({
+      method: 'GET',
+      path: '/orders/1',
+    });
+  });
+
+  it('attributes unexpected requests to every interaction', () => {
+    const unexpected: ContractMismatch = {
+      kind: 'request-not-found',
+      request: { method: 'GET', path: '/users' },
+      mismatch: 'The request was not expected',
+    };
+    const suite = consumerTestSuite(
+      'Pact between a and b',
+      interactions,
+      started,
+      [unexpected]
+    );
+
+    expect(suite.testCases.map((t) => t.mismatches)).to.deep.eq([
+      [unexpected],
+      [unexpected],
+    ]);
+  });
+
+  it('fails every interaction when the test throws', () => {
+    const suite = consumerTestSuite(
+      'Pact between a and b',
+      interactions,
+      started,
+      [],
+      new Error('boom')
+    );
+
+    expect(suite.testCases.map((t) => [t.status, t.failure])).to.deep.eq([
+      ['failed', 'boom'],
+      ['failed', 'boom'],
+    ]);
+  });
+});
+
+describe('#recordedRequest', () => {
+  it('records the example values of the query and headers', () => {
+    expect(
+      recordedRequest({
+        method: 'GET',
+        path: '/orders',
+        query: { page: integer(1), status: ['open', 'closed'] },
+        headers: { Accept: regex('application/.*json', 'application/json') },
+      })
+    ).to.deep.include({
+      query: { page: ['1'], status: ['open', 'closed'] },
+      headers: { Accept: ['application/json'] },
+    });
+  });
+});
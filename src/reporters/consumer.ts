@@ -0,0 +1,165 @@
+import {
+  ContractMismatch,
+  mismatchSummary,
+} from '../errors/contractMismatchError';
+import {
+  TemplateHeaders,
+  TemplateQuery,
+  V3RegexMatcher,
+  V3Request,
+} from '../v3/types';
+import { isMatcher, reify } from '../v3/matchers';
+import { ExpectedRequest, isExpectedRequest } from '../v3/display';
+import { report } from './index';
+import { Reporter, TestCaseResult, TestSuiteResult } from './types';
+
+/**
+ * The request of an interaction added to a consumer test
+ */
+export type RecordedRequest = ExpectedRequest;
+
+/**
+ * What we know about an interaction added to a consumer test
+ */
+export interface RecordedInteraction {
+  description: string;
+  providerStates: string[];
+  request?: RecordedRequest;
+}
+
+/**
+ * The example values of query parameters or headers, in the same form as the
+ * mock server reports the requests it received
+ */
+export const recordedValues = (
+  values: TemplateQuery | TemplateHeaders | undefined
+): Record<string, string[]> | undefined =>
+  values &&
+  Object.keys(values).reduce<Record<string, string[]>>(
+    (acc, key) => ({
+      ...acc,
+      [key]: ([] as unknown[]).concat(values[key]).map((v) => `${reify(v)}`),
+    }),
+    {}
+  );
+
+export const recordedRequest = (
+  request: Pick<V3Request, 'method' | 'path' | 'query' | 'headers'>,
+  body?: unknown
+): RecordedRequest => ({
+  method: request.method,
+  path: reify(request.path),
+  pattern:
+    isMatcher(request.path) && request.path['pact:matcher:type'] === 'regex'
+      ? (request.path as V3RegexMatcher).regex
+      : undefined,
+  query: recordedValues(request.query),
+  headers: recordedValues(request.headers),
+  body,
+});
+
+/**
+ * The requests of the interactions, to match them to the mock server's results
+ */
+export const expectedRequests = (
+  interactions: RecordedInteraction[]
+): RecordedRequest[] =>
+  interactions.flatMap((i) => (i.request ? [i.request] : []));
+
+// The core's mismatches identify the request they relate to by its method and
+// path, rather than by the interaction's description. Mismatches that relate
+// to an interaction's request are attributed to it. Anything else, such as an
+// unexpected request, could have been caused by any interaction in the test,
+// so is attributed to all of them.
+const mismatchesFor = (
+  interaction: RecordedInteraction,
+  interactions: RecordedInteraction[],
+  mismatches: ContractMismatch[]
+): ContractMismatch[] =>
+  mismatches.filter((m) => {
+    const { request } = m;
+    if (!request) {
+      return true;
+    }
+    if (
+      interaction.request &&
+      isExpectedRequest(interaction.request, request)
+    ) {
+      return true;
+    }
+    return !interactions.some(
+      (i) => i.request && isExpectedRequest(i.request, request)
+    );
+  });
+
+const testCaseName = (interaction: RecordedInteraction): string => {
+  if (interaction.description) {
+    return interaction.description;
+  }
+  return interaction.request
+    ? `${interaction.request.method} ${interaction.request.path}`
+    : 'Interaction';
+};
+
+/**
+ * Reports each interaction in a consumer test as a test case. Interactions
+ * are exercised together, so each takes the duration of the whole test
+ */
+export const consumerTestSuite = (
+  name: string,
+  interactions: RecordedInteraction[],
+  started: Date,
+  mismatches: ContractMismatch[],
+  error?: Error
+): TestSuiteResult => {
+  const durationMs = Date.now() - started.getTime();
+
+  const testCases = interactions.map((interaction): TestCaseResult => {
+    const own = mismatchesFor(interaction, interactions, mismatches);
+    const failed = own.length > 0 || error !== undefined;
+
+    return {
+      name: testCaseName(interaction),
+      providerStates: interaction.providerStates,
+      request: interaction.request && {
+        method: interaction.request.method,
+        path: interaction.request.path,
+      },
+      durationMs,
+      status: failed ? 'failed' : 'passed',
+      mismatches: own,
+      failure:
+        error?.message ??
+        (own.length > 0 ? mismatchSummary(own) : undefined),
+    };
+  });
+
+  return {
+    name,
+    role: 'consumer',
+    timestamp: started.toISOString(),
+    durationMs,
+    testCases,
+  };
+};
+
+/**
+ * Reports the interactions in a consumer test to the configured reporters
+ */
+export const reportConsumerTest = (
+  opts: { consumer: string; provider: string; reporters?: Reporter[] },
+  interactions: RecordedInteraction[],
+  started: Date,
+  mismatches: ContractMismatch[],
+  error?: Error
+): Promise<void> =>
+  report(
+    opts.reporters,
+    consumerTestSuite(
+      `Pact between ${opts.consumer} and ${opts.provider}`,
+      interactions,
+      started,
+      mismatches,
+      error
+    )
+  );
@@ -0,0 +1,139 @@
+import chai from 'chai';
+import { spawnSync } from 'child_process';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { jsonReporter, toJsonReport } from './json';
+import { junitReporter } from './junit';
+import { TestSuiteResult } from './types';
+
+const { expect } = chai;
+
+describe('#jsonReporter', () => {
+  const file = path.join(
+    os.tmpdir(),
+    `pact-reporters-${process.pid}`,
+    'report.json'
+  );
+  const suite = (name: string, status: 'passed' | 'failed') =>
+    ({
+      name: 'Pact between a and b',
+      role: 'consumer',
+      timestamp: '2024-01-01T00:00:00.000Z',
+      durationMs: 10,
+      testCases: [
+        { name, providerStates: [], durationMs: 10, status, mismatches: [] },
+      ],
+    } as TestSuiteResult);
+
+  afterEach(() => {
+    fs.rmSync(path.dirname(file), { recursive: true, force: true });
+  });
+
+  it('writes a summary of every test reported to the file', () => {
+    jsonReporter(file).report(suite('first', 'passed'));
+    jsonReporter(file).report(suite('second', 'failed'));
+
+    const report = JSON.parse(fs.readFileSync(file, 'utf8'));
+
+    expect(report.summary).to.deep.eq({
+      tests: 2,
+      passed: 1,
+      failed: 1,
+      unknown: 0,
+    });
+    expect(report.suites).to.have.lengthOf(1);
+    expect(report.suites[0].durationMs).to.eq(20);
+    expect(
+      report.suites[0].testCases.map((t: { name: string }) => t.name)
+    ).to.deep.eq(['first', 'second']);
+  });
+
+  it('adds to the results reported by another process in the run', () => {
+    const results = `${file}.results`;
+    fs.mkdirSync(results, { recursive: true });
+    // The parent process is running, so its results are from this run
+    fs.writeFileSync(
+      path.join(results, `${process.ppid}.json`),
+      JSON.stringify({
+        started: 0,
+        updated: Date.now(),
+        suites: [suite('first', 'passed')],
+      })
+    );
+
+    jsonReporter(file).report(suite('second', 'passed'));
+
+    const report = JSON.parse(fs.readFileSync(file, 'utf8'));
+    expect(report.summary.tests).to.eq(2);
+  });
+
+  it('replaces the results of an earlier run', () => {
+    const results = `${file}.results`;
+    const { pid } = spawnSync(process.execPath, ['-e', '']);
+    fs.mkdirSync(results, { recursive: true });
+    fs.writeFileSync(
+      path.join(results, `${pid}.json`),
+      JSON.stringify({
+        started: 0,
+        updated: 0,
+        suites: [suite('first', 'passed')],
+      })
+    );
+
+    jsonReporter(file).report(suite('second', 'passed'));
+
+    const report = JSON.parse(fs.readFileSync(file, 'utf8'));
+    expect(report.summary.tests).to.eq(1);
+    expect(report.suites[0].durationMs).to.eq(10);
+    expect(fs.existsSync(path.join(results, `${pid}.json`))).to.eq(false);
+  });
+
+  it('replaces a test that was already reported', () => {
+    jsonReporter(file).report(suite('first', 'failed'));
+    jsonReporter(file).report(suite('first', 'passed'));
+
+    const report = JSON.parse(fs.readFileSync(file, 'utf8'));
+    expect(report.summary).to.include({ tests: 1, passed: 1 });
+  });
+});
+
+describe('#junitReporter', () => {
+  const file = path.join(
+    os.tmpdir(),
+    `pact-junit-reporters-${process.pid}`,
+    'report.xml'
+  );
+  const suite = (name: string) =>
+    ({
+      name: 'Pact between a and b',
+      role: 'consumer',
+      timestamp: '2024-01-01T00:00:00.000Z',
+      durationMs: 10,
+      testCases: [
+        {
+          name,
+          providerStates: [],
+          durationMs: 10,
+          status: 'passed',
+          mismatches: [],
+        },
+      ],
+    } as TestSuiteResult);
+
+  after(() => {
+    fs.rmSync(path.dirname(file), { recursive: true, force: true });
+  });
+
+  it('keeps the results of the process alongside the report', () => {
+    junitReporter(file).report(suite('first'));
+    junitReporter(file).report(suite('second'));
+
+    const xml = fs.readFileSync(file, 'utf8');
+    expect(xml).to.contain('<testcase name="first"');
+    expect(xml).to.contain('<testcase name="second"');
+    expect(fs.readdirSync(`${file}.results`)).to.deep.eq([
+      `${process.pid}.json`,
+    ]);
+  });
+});
@@ -0,0 +1,177 @@
+import fs = require('fs');
+import path = require('path');
+import { Reporter, TestSuiteResult } from './types';
+
+// How many times the report is rebuilt when other processes keep reporting
+// while it is being written
+const MAX_REBUILDS = 10;
+
+// Test runners such as Jest reuse processes for different test files, and
+// give each its own module registry, so the start of the process rather than
+// of this module is used to tell runs apart
+const processStarted = Date.now() - Math.round(process.uptime() * 1000);
+
+// The start times of the same process, as seen from different module
+// registries, are within this of each other
+const STARTED_TOLERANCE_MS = 1000;
+
+/**
+ * The results reported by one process, kept alongside the report
+ */
+interface ProcessResults {
+  /** When the process started, in milliseconds since the epoch */
+  started: number;
+  /** When the process last reported */
+  updated: number;
+  suites: TestSuiteResult[];
+}
+
+// Written to a temporary file and renamed, so other processes never read a
+// partly written file
+const writeAtomically = (file: string, content: string): void => {
+  const temp = `${file}.${process.pid}.tmp`;
+  fs.writeFileSync(temp, content);
+  fs.renameSync(temp, file);
+};
+
+const readResults = (file: string): ProcessResults | undefined => {
+  try {
+    return JSON.parse(fs.readFileSync(file, 'utf8'));
+  } catch (e) {
+    return undefined;
+  }
+};
+
+const isRunning = (pid: number): boolean => {
+  try {
+    process.kill(pid, 0);
+    return true;
+  } catch (e) {
+    // The process exists, but belongs to another user
+    return e.code === 'EPERM';
+  }
+};
+
+const testCaseKey = (t: TestSuiteResult['testCases'][number]): string =>
+  JSON.stringify([t.name, t.providerStates]);
+
+// Results for the same consumer and provider are reported once per test, so
+// are combined into a single suite. A test case that was already reported in
+// the run is replaced.
+export const mergeSuite = (
+  suites: TestSuiteResult[],
+  suite: TestSuiteResult
+): TestSuiteResult[] => {
+  const existing = suites.find(
+    (s) => s.name === suite.name && s.role === suite.role
+  );
+
+  if (!existing) {
+    return [...suites, suite];
+  }
+
+  const replaced = suite.testCases.map(testCaseKey);
+
+  return suites.map((s) =>
+    s === existing
+      ? {
+          ...s,
+          durationMs: s.durationMs + suite.durationMs,
+          testCases: [
+            ...s.testCases.filter((t) => !replaced.includes(testCaseKey(t))),
+            ...suite.testCases,
+          ],
+        }
+      : s
+  );
+};
+
+// The results of each process that reported in this run, by file name. The
+// run started when the earliest of the processes that are still running did,
+// so results last reported before then are from an earlier run, and are
+// removed
+const resultsOfRun = (dir: string): Record<string, ProcessResults> => {
+  const all = fs
+    .readdirSync(dir)
+    .filter((f) => /^\d+\.json$/.test(f))
+    .reduce<Record<string, ProcessResults>>((acc, f) => {
+      const results = readResults(path.join(dir, f));
+      return results ? { ...acc, [f]: results } : acc;
+    }, {});
+  const runStarted = Math.min(
+    processStarted,
+    ...Object.keys(all)
+      .filter((f) => isRunning(parseInt(f, 10)))
+      .map((f) => all[f].started)
+  );
+
+  return Object.keys(all).reduce<Record<string, ProcessResults>>((acc, f) => {
+    if (all[f].updated < runStarted) {
+      fs.rmSync(path.join(dir, f), { force: true });
+      return acc;
+    }
+    return { ...acc, [f]: all[f] };
+  }, {});
+};
+
+const suitesOf = (run: Record<string, ProcessResults>): TestSuiteResult[] =>
+  Object.values(run)
+    .sort((a, b) => a.started - b.started)
+    .reduce<TestSuiteResult[]>(
+      (acc, results) => results.suites.reduce(mergeSuite, acc),
+      []
+    );
+
+/**
+ * Creates a reporter that writes the results of the run to a file after each
+ * suite, so the file is complete however the run ends.
+ *
+ * Each process keeps its results in a file of its own, in a `.results`
+ * directory alongside the report, and the report is rebuilt from the results
+ * of every process in the run. A new run replaces the report.
+ *
+ * @param file the report to write
+ * @param render renders the results as the content of the report
+ */
+export const fileReporter = (
+  file: string,
+  render: (suites: TestSuiteResult[]) => string
+): Reporter => ({
+  report(suite: TestSuiteResult) {
+    const target = path.resolve(file);
+    const dir = `${target}.results`;
+    const own = path.join(dir, `${process.pid}.json`);
+
+    fs.mkdirSync(dir, { recursive: true });
+
+    // A file from an earlier process with the same id is from another run
+    const previous = readResults(own);
+    const reported =
+      previous &&
+      Math.abs(previous.started - processStarted) <= STARTED_TOLERANCE_MS
+        ? previous.suites
+        : [];
+    writeAtomically(
+      own,
+      JSON.stringify({
+        started: processStarted,
+        updated: Date.now(),
+        suites: mergeSuite(reported, suite),
+      })
+    );
+
+    // Another process may report between reading the results and writing
+    // the report, replacing its report with this one, so the report is
+    // rebuilt until the results it was built from are unchanged
+    let run = resultsOfRun(dir);
+    for (let i = 0; i < MAX_REBUILDS; i += 1) {
+      writeAtomically(target, render(suitesOf(run)));
+
+      const latest = resultsOfRun(dir);
+      if (JSON.stringify(latest) === JSON.stringify(run)) {
+        return;
+      }
+      run = latest;
+    }
+  },
+});
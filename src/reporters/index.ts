@@ -0,0 +1,27 @@
+/**
+ * Reporters for consumer test and provider verification results
+ * @module reporters
+ */
+import logger from '../common/logger';
+import { Reporter, TestSuiteResult } from './types';
+
+export * from './types';
+export { junitReporter, toJUnitXml } from './junit';
+export { jsonReporter, toJsonReport, JsonReport } from './json';
+
+/**
+ * Sends the results to each reporter. A reporter that fails is logged rather
+ * than failing the test
+ */
+export const report = async (
+  reporters: Reporter[] | undefined,
+  suite: TestSuiteResult
+): Promise<void> => {
+  await Promise.all(
+    (reporters ?? []).map((reporter) =>
+      Promise.resolve()
+        .then(() => reporter.report(suite))
+        .catch((e) => logger.error(`unable to report results: ${e.message}`))
+    )
+  );
+};
@@ -0,0 +1,36 @@
+import { fileReporter } from './file';
+import { Reporter, TestCaseStatus, TestSuiteResult } from './types';
+
+export interface JsonReport {
+  summary: Record<TestCaseStatus, number> & { tests: number };
+  suites: TestSuiteResult[];
+}
+
+/**
+ * Summarises test suites as a JSON report
+ */
+export const toJsonReport = (suites: TestSuiteResult[]): JsonReport => {
+  const testCases = suites.flatMap((s) => s.testCases);
+  const count = (status: TestCaseStatus) =>
+    testCases.filter((t) => t.status === status).length;
+
+  return {
+    summary: {
+      tests: testCases.length,
+      passed: count('passed'),
+      failed: count('failed'),
+      unknown: count('unknown'),
+    },
+    suites,
+  };
+};
+
+/**
+ * Writes a JSON summary of each interaction to the given file
+ *
+ * @param file path to the report, e.g. `reports/pact.json`
+ */
+export const jsonReporter = (file: string): Reporter =>
+  fileReporter(file, (suites) =>
+    JSON.stringify(toJsonReport(suites), null, 2)
+  );
@@ -0,0 +1,72 @@
+import chai from 'chai';
+import { toJUnitXml } from './junit';
+import { TestSuiteResult } from './types';
+
+const { expect } = chai;
+
+describe('#toJUnitXml', () => {
+  const suite: TestSuiteResult = {
+    name: 'Pact between a & b',
+    role: 'consumer',
+    timestamp: '2024-01-01T00:00:00.000Z',
+    durationMs: 1500,
+    testCases: [
+      {
+        name: 'a request for an order',
+        providerStates: ['an order exists'],
+        durationMs: 1500,
+        status: 'passed',
+        mismatches: [],
+      },
+      {
+        name: 'a request to create an order',
+        providerStates: [],
+        durationMs: 1500,
+        status: 'failed',
+        mismatches: [
+          {
+            kind: 'request-mismatch',
+            request: { method: 'POST', path: '/orders' },
+            path: '$.id',
+            mismatch: 'Expected "2" to be equal to "1"',
+          },
+        ],
+        failure: '1 mismatch with the contract',
+      },
+      {
+        name: 'GET /orders',
+        providerStates: [],
+        durationMs: 20,
+        status: 'unknown',
+        mismatches: [],
+      },
+    ],
+  };
+
+  it('renders each test case in its suite', () => {
+    expect(toJUnitXml([suite])).to.eq(
+      [
+        '<?xml version="1.0" encoding="UTF-8"?>',
+        '<testsuites tests="3" failures="1" skipped="1" time="1.500">',
+        '  <testsuite name="Pact between a &amp; b" tests="3" failures="1" skipped="1" timestamp="2024-01-01T00:00:00.000Z" time="1.500">',
+        '    <testcase name="a request for an order" classname="Pact between a &amp; b" time="1.500">',
+        '      <properties>',
+        '        <property name="providerState" value="an order exists"/>',
+        '      </properties>',
+        '    </testcase>',
+        '    <testcase name="a request to create an order" classname="Pact between a &amp; b" time="1.500">',
+        '      <failure message="1 mismatch with the contract">ContractMismatchError: 1 mismatch with the contract',
+        '',
+        '  1) The following request was incorrect: POST /orders',
+        '     $.id: Expected &quot;2&quot; to be equal to &quot;1&quot;</failure>',
+        '    </testcase>',
+        '    <testcase name="GET /orders" classname="Pact between a &amp; b" time="0.020">',
+        '      <skipped message="The verifier did not report the outcome of this interaction"/>',
+        '    </testcase>',
+        '  </testsuite>',
+        '</testsuites>',
+        '',
+      ].join('\n')
+    );
+  });
+});
@@ -0,0 +1,108 @@
+import ContractMismatchError from '../errors/contractMismatchError';
+import { fileReporter } from './file';
+import { Reporter, TestCaseResult, TestSuiteResult } from './types';
+
+const XML_ENTITIES: Record<string, string> = {
+  '&': '&amp;',
+  '<': '&lt;',
+  '>': '&gt;',
+  '"': '&quot;',
+  "'": '&apos;',
+};
+
+// Escapes text for an attribute or element, removing the control characters
+// that are not allowed in XML
+const escape = (text: string): string =>
+  text
+    // eslint-disable-next-line no-control-regex
+    .replace(/[\u0000-\u0008\u000b\u000c\u000e-\u001f]/g, '')
+    .replace(/[&<>"']/g, (c) => XML_ENTITIES[c]);
+
+const seconds = (ms: number): string => (ms / 1000).toFixed(3);
+
+const count = (testCases: TestCaseResult[], status: string): number =>
+  testCases.filter((t) => t.status === status).length;
+
+const counts = (testCases: TestCaseResult[]): string =>
+  `tests="${testCases.length}" failures="${count(
+    testCases,
+    'failed'
+  )}" skipped="${count(testCases, 'unknown')}"`;
+
+const failureDetails = (testCase: TestCaseResult): string =>
+  testCase.mismatches.length > 0
+    ? new ContractMismatchError(
+        testCase.failure ?? 'Mismatches',
+        testCase.mismatches
+      ).toString()
+    : testCase.failure ?? '';
+
+const renderTestCase = (
+  testCase: TestCaseResult,
+  suite: TestSuiteResult
+): string[] => {
+  const lines = [
+    `    <testcase name="${escape(testCase.name)}" classname="${escape(
+      suite.name
+    )}" time="${seconds(testCase.durationMs)}">`,
+  ];
+
+  if (testCase.providerStates.length > 0) {
+    lines.push(
+      '      <properties>',
+      ...testCase.providerStates.map(
+        (state) =>
+          `        <property name="providerState" value="${escape(state)}"/>`
+      ),
+      '      </properties>'
+    );
+  }
+
+  if (testCase.status === 'failed') {
+    lines.push(
+      `      <failure message="${escape(
+        testCase.failure ?? 'Failed'
+      )}">${escape(failureDetails(testCase))}</failure>`
+    );
+  } else if (testCase.status === 'unknown') {
+    lines.push(
+      '      <skipped message="The verifier did not report the outcome of this interaction"/>'
+    );
+  }
+
+  lines.push('    </testcase>');
+
+  return lines;
+};
+
+const renderSuite = (suite: TestSuiteResult): string[] => [
+  `  <testsuite name="${escape(suite.name)}" ${counts(
+    suite.testCases
+  )} timestamp="${suite.timestamp}" time="${seconds(suite.durationMs)}">`,
+  ...suite.testCases.flatMap((testCase) => renderTestCase(testCase, suite)),
+  '  </testsuite>',
+];
+
+/**
+ * Renders test suites as JUnit XML
+ */
+export const toJUnitXml = (suites: TestSuiteResult[]): string => {
+  const testCases = suites.flatMap((s) => s.testCases);
+  const time = suites.reduce((total, s) => total + s.durationMs, 0);
+
+  return [
+    '<?xml version="1.0" encoding="UTF-8"?>',
+    `<testsuites ${counts(testCases)} time="${seconds(time)}">`,
+    ...suites.flatMap(renderSuite),
+    '</testsuites>',
+    '',
+  ].join('\n');
+};
+
+/**
+ * Writes a JUnit XML report of each interaction to the given file
+ *
+ * @param file path to the report, e.g. `reports/pact-junit.xml`
+ */
+export const junitReporter = (file: string): Reporter =>
+  fileReporter(file, toJUnitXml);
@@ -0,0 +1,47 @@
+import { ContractMismatch } from '../errors/contractMismatchError';
+
+/**
+ * Outcome of a single interaction. The provider verifier does not tell us
+ * the outcome of each interaction, so when a verification fails, the
+ * interactions that did not fail in pact-js are `unknown`
+ */
+export type TestCaseStatus = 'passed' | 'failed' | 'unknown';
+
+/**
+ * The result of exercising a single interaction
+ */
+export interface TestCaseResult {
+  /** The interaction description, or its request when there isn't one */
+  name: string;
+  providerStates: string[];
+  request?: { method: string; path: string };
+  /** Time taken in milliseconds */
+  durationMs: number;
+  status: TestCaseStatus;
+  /** Mismatches reported by the mock server for this interaction */
+  mismatches: ContractMismatch[];
+  /** Why the interaction failed */
+  failure?: string;
+}
+
+/**
+ * The results of a consumer test or provider verification run
+ */
+export interface TestSuiteResult {
+  /** e.g. "Pact between MyConsumer and MyProvider" */
+  name: string;
+  role: 'consumer' | 'provider';
+  /** When the run started, as an ISO 8601 date */
+  timestamp: string;
+  /** Time taken in milliseconds */
+  durationMs: number;
+  testCases: TestCaseResult[];
+}
+
+/**
+ * Receives the results of each consumer test (`executeTest`) or provider
+ * verification (`verifyProvider`)
+ */
+export interface Reporter {
+  report(suite: TestSuiteResult): void | Promise<void>;
+}
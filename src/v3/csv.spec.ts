@@ -0,0 +1,116 @@
+import * as chai from 'chai';
+import { csvPluginContents, csvTemplate } from './csv';
+import * as MatchersV3 from './matchers';
+
+const { expect } = chai;
+
+describe('CSV templates', () => {
+  describe('#csvTemplate', () => {
+    it('generates an example document with a header row', () => {
+      const result = csvTemplate(
+        {
+          id: MatchersV3.integer(1),
+          name: MatchersV3.string('fred'),
+          country: 'AU',
+        },
+        { rows: 2 }
+      );
+
+      expect(result['pact:matcher:type']).to.eq('regex');
+      expect(result.value).to.eq('id,name,country\n1,fred,AU\n1,fred,AU');
+    });
+
+    it('generates a regular expression that matches the example', () => {
+      const result = csvTemplate({
+        id: MatchersV3.integer(1),
+        price: MatchersV3.decimal(1.5),
+        sku: MatchersV3.regex(/^[A-Z]{3}$/, 'ABC'),
+      });
+
+      expect(result.value).to.match(new RegExp(result.regex));
+    });
+
+    it('generates a regular expression that matches other rows of the same shape', () => {
+      const result = csvTemplate({
+        id: MatchersV3.integer(1),
+        name: MatchersV3.string('fred'),
+        sku: MatchersV3.regex('[A-Z]{3}', 'ABC'),
+      });
+      const pattern = new RegExp(result.regex);
+
+      expect('id,name,sku\r\n2,mary,XYZ\r\n33,,DEF\r\n').to.match(pattern);
+      expect('id,name,sku\n2,mary,xyz').not.to.match(pattern);
+      expect('id,name,sku\nabc,mary,XYZ').not.to.match(pattern);
+      expect('name,id,sku\nmary,2,XYZ').not.to.match(pattern);
+    });
+
+    it('omits the header row if requested', () => {
+      const result = csvTemplate(
+        { id: MatchersV3.integer(1), name: 'fred' },
+        { header: false, separator: ';' }
+      );
+
+      expect(result.value).to.eq('1;fred');
+      expect('2;fred\n3;fred').to.match(new RegExp(result.regex));
+    });
+
+    it('throws an exception for matchers that cannot be applied to a column', () => {
+      expect(() => csvTemplate({ ids: MatchersV3.eachLike(1) })).to.throw(
+        /not supported in CSV bodies/
+      );
+      expect(() =>
+        csvTemplate({ ids: MatchersV3.eachKeyMatches({ a: 'b' }) })
+      ).to.throw(/not supported in CSV bodies/);
+    });
+
+    it('throws an exception if an example contains the separator', () => {
+      expect(() => csvTemplate({ name: 'smith, fred' })).to.throw(
+        /must not contain the separator/
+      );
+    });
+
+    it('throws an exception if there are no columns', () => {
+      expect(() => csvTemplate({})).to.throw();
+    });
+  });
+
+  describe('#csvPluginContents', () => {
+    it('generates a matching rule for each column by name', () => {
+      const result = csvPluginContents({
+        id: MatchersV3.integer(1),
+        name: MatchersV3.string("O'Brien"),
+        sku: MatchersV3.regex(/^[A-Z]{3}$/, 'ABC'),
+        created: MatchersV3.date('yyyy-MM-dd', '2000-01-01'),
+        country: 'AU',
+      });
+
+      expect(JSON.parse(result)).to.deep.eq({
+        csvHeaders: true,
+        'column:id': 'matching(integer,1)',
+        'column:name': "matching(type,'O\\'Brien')",
+        'column:sku': "matching(regex,'^[A-Z]{3}$','ABC')",
+        'column:created': "matching(date,'yyyy-MM-dd','2000-01-01')",
+        'column:country': "matching(equalTo,'AU')",
+      });
+    });
+
+    it('numbers the columns of a document without a header row', () => {
+      const result = csvPluginContents(
+        { id: MatchersV3.integer(1), name: MatchersV3.string('fred') },
+        { header: false }
+      );
+
+      expect(JSON.parse(result)).to.deep.eq({
+        csvHeaders: false,
+        'column:1': 'matching(integer,1)',
+        'column:2': "matching(type,'fred')",
+      });
+    });
+
+    it('rejects matchers the plugin does not support', () => {
+      expect(() =>
+        csvPluginContents({ ids: MatchersV3.eachLike(1) })
+      ).to.throw(/not supported by the CSV plugin/);
+    });
+  });
+});
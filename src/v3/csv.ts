@@ -0,0 +1,236 @@
+import { times } from 'ramda';
+import { isMatcher, regex, reify } from './matchers';
+import { DateTimeMatcher, Matcher, V3RegexMatcher } from './types';
+import { escapeRegex, stripAnchors } from './regex';
+import ConfigurationError from '../errors/configurationError';
+
+/**
+ * Columns of a CSV document, in order. The key is the column name (used for
+ * the header row) and the value is the example value or a matcher for it.
+ */
+export type CsvColumns = Record<
+  string,
+  string | number | boolean | Matcher<unknown>
+>;
+
+export interface CsvOptions {
+  /**
+   * If the first row of the document contains the column names. Defaults to true
+   */
+  header?: boolean;
+  /**
+   * Number of example rows to generate. Defaults to 1
+   */
+  rows?: number;
+  /**
+   * Column separator. Defaults to ','
+   */
+  separator?: string;
+}
+
+const columnPattern = (
+  name: string,
+  column: CsvColumns[string],
+  separator: string
+): string => {
+  const anyValue = `[^${escapeRegex(separator)}\\r\\n]`;
+  const unsupported = (type: string) =>
+    new ConfigurationError(
+      `CSV column '${name}' uses the '${type}' matcher, which is not supported in CSV bodies`
+    );
+
+  if (!isMatcher(column)) {
+    if (typeof column === 'object') {
+      throw new ConfigurationError(
+        `CSV column '${name}' must be a string, number, boolean or a matcher`
+      );
+    }
+    return escapeRegex(`${column}`);
+  }
+
+  switch (column['pact:matcher:type']) {
+    case 'regex':
+      return `(?:${stripAnchors((column as V3RegexMatcher).regex)})`;
+    case 'integer':
+      return '-?\\d+';
+    case 'decimal':
+      return '-?\\d+\\.\\d+';
+    case 'number':
+      return '-?\\d+(?:\\.\\d+)?';
+    case 'equality':
+      return escapeRegex(`${column.value}`);
+    case 'include':
+      return `${anyValue}*${escapeRegex(`${column.value}`)}${anyValue}*`;
+    case 'type':
+      if (typeof column.value === 'number') {
+        return '-?\\d+(?:\\.\\d+)?';
+      }
+      if (typeof column.value === 'boolean') {
+        return '(?:true|false)';
+      }
+      if (typeof column.value === 'string') {
+        return `${anyValue}*`;
+      }
+      throw unsupported('type');
+    case 'timestamp':
+    case 'date':
+    case 'time':
+      // Date formats can't be expressed as a regex, so only require a value
+      return `${anyValue}+`;
+    default:
+      throw unsupported(column['pact:matcher:type']);
+  }
+};
+
+const columnExample = (
+  name: string,
+  column: CsvColumns[string],
+  separator: string
+): string => {
+  const example = `${reify(column)}`;
+
+  if (example.includes(separator) || /[\r\n]/.test(example)) {
+    throw new ConfigurationError(
+      `The example for CSV column '${name}' must not contain the separator or a new line`
+    );
+  }
+
+  return example;
+};
+
+/**
+ * Creates a template for a CSV document, where each column has its own matcher.
+ * The template is a regular expression for the whole document, which can be used
+ * as a text/csv body.
+ *
+ * The core only sees the one regular expression, so the matchers of the columns
+ * are not recorded in the pact, and a mismatch is reported against the whole
+ * document. Use the CSV plugin, with {@link csvPluginContents}, for rules on
+ * each column.
+ *
+ * @param columns Columns of the document, in order
+ * @param options See {@link CsvOptions}
+ */
+export function csvTemplate(
+  columns: CsvColumns,
+  options: CsvOptions = {}
+): V3RegexMatcher {
+  const { header = true, rows = 1, separator = ',' } = options;
+  const names = Object.keys(columns);
+
+  if (names.length === 0) {
+    throw new ConfigurationError('A CSV body must have at least one column');
+  }
+  if (rows < 1) {
+    throw new ConfigurationError('A CSV body must have at least one row');
+  }
+
+  const rowPattern = names
+    .map((name) => columnPattern(name, columns[name], separator))
+    .join(escapeRegex(separator));
+  const rowExample = names
+    .map((name) => columnExample(name, columns[name], separator))
+    .join(separator);
+
+  const headerPattern = header
+    ? `${names.map(escapeRegex).join(escapeRegex(separator))}\\r?\\n`
+    : '';
+  const headerExample = header ? [names.join(separator)] : [];
+
+  return regex(
+    `^${headerPattern}${rowPattern}(?:\\r?\\n${rowPattern})*(?:\\r?\\n)?$`,
+    [...headerExample, ...times(() => rowExample, rows)].join('\n')
+  );
+}
+
+// A string in a matching rule expression of the core
+const quoted = (value: unknown): string =>
+  `'${`${value}`.replace(/\\/g, '\\\\').replace(/'/g, "\\'")}'`;
+
+// A primitive value in a matching rule expression of the core
+const expressionValue = (value: unknown): string =>
+  typeof value === 'string' ? quoted(value) : `${value}`;
+
+const columnExpression = (name: string, column: CsvColumns[string]): string => {
+  const unsupported = (type: string) =>
+    new ConfigurationError(
+      `CSV column '${name}' uses the '${type}' matcher, which is not supported by the CSV plugin`
+    );
+
+  if (!isMatcher(column)) {
+    if (typeof column === 'object') {
+      throw new ConfigurationError(
+        `CSV column '${name}' must be a string, number, boolean or a matcher`
+      );
+    }
+    return `matching(equalTo,${expressionValue(column)})`;
+  }
+
+  const type = column['pact:matcher:type'];
+  const example = reify(column);
+
+  switch (type) {
+    case 'regex': {
+      const pattern = (column as V3RegexMatcher).regex;
+      return `matching(regex,${quoted(pattern)},${quoted(example)})`;
+    }
+    case 'integer':
+    case 'decimal':
+    case 'number':
+    case 'type':
+      if (typeof example === 'object') {
+        throw unsupported(type);
+      }
+      return `matching(${type},${expressionValue(example)})`;
+    case 'equality':
+      return `matching(equalTo,${expressionValue(example)})`;
+    case 'include':
+      return `matching(include,${quoted(example)})`;
+    case 'notEmpty':
+      return `notEmpty(${expressionValue(example)})`;
+    case 'timestamp':
+    case 'date':
+    case 'time': {
+      const rule = type === 'timestamp' ? 'datetime' : type;
+      const { format } = column as DateTimeMatcher;
+      return `matching(${rule},${quoted(format)},${quoted(example)})`;
+    }
+    default:
+      throw unsupported(type);
+  }
+};
+
+/**
+ * Creates the contents of an interaction for the
+ * [CSV plugin](https://github.com/pact-foundation/pact-plugins/tree/main/plugins/csv),
+ * with a matching rule for each column, to use with `usingPlugin` and
+ * `pluginContents` (`withPluginContents` for messages) and the `text/csv`
+ * content type.
+ *
+ * @param columns Columns of the document, in order
+ * @param options Only `header` is supported, as the plugin generates the rows
+ */
+export function csvPluginContents(
+  columns: CsvColumns,
+  options: Pick<CsvOptions, 'header'> = {}
+): string {
+  const { header = true } = options;
+  const names = Object.keys(columns);
+
+  if (names.length === 0) {
+    throw new ConfigurationError('A CSV body must have at least one column');
+  }
+
+  return JSON.stringify(
+    names.reduce<Record<string, string | boolean>>(
+      (contents, name, i) => ({
+        ...contents,
+        [`column:${header ? name : i + 1}`]: columnExpression(
+          name,
+          columns[name]
+        ),
+      }),
+      { csvHeaders: header }
+    )
+  );
+}
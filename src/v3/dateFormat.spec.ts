@@ -0,0 +1,76 @@
+import * as chai from 'chai';
+import { matchesDateFormat, validateDateFormat } from './dateFormat';
+
+const { expect } = chai;
+
+describe('Date formats', () => {
+  describe('#matchesDateFormat', () => {
+    const valid: [string, string][] = [
+      ['yyyy-MM-dd', '2020-02-29'],
+      ["yyyy-MM-dd'T'HH:mm:ss.SSSX", '2016-02-11T09:46:56.023Z'],
+      ["yyyy-MM-dd'T'HH:mm:ssXXX", '2016-02-11T09:46:56+10:00'],
+      ['EEE, dd MMM yyyy HH:mm:ss Z', 'Tue, 01 Dec 2020 10:00:00 +1100'],
+      ['EEEE d MMMM yyyy', 'Tuesday 1 December 2020'],
+      ['yyyyMMdd', '20200131'],
+      ['h:mm a', '10:30 PM'],
+      ["hh 'o''clock' a", "12 o'clock PM"],
+    ];
+    const invalid: [string, string][] = [
+      ['yyyy-MM-dd', '2021-02-29'],
+      ['yyyy-MM-dd', '2020-13-01'],
+      ['yyyy-MM-dd', '01/01/2020'],
+      ["yyyy-MM-dd'T'HH:mm:ssXXX", '2016-02-11T09:46:56+1000'],
+      ['HH:mm:ss', '24:00:00'],
+      ['yyyyMMdd', '20200132'],
+      ['h:mm a', '13:30 PM'],
+      ['dd MMM yyyy', '01 Dex 2020'],
+    ];
+
+    valid.forEach(([format, value]) => {
+      it(`matches '${value}' with the format '${format}'`, () => {
+        expect(matchesDateFormat(format, value)).to.eq(true);
+      });
+    });
+
+    invalid.forEach(([format, value]) => {
+      it(`does not match '${value}' with the format '${format}'`, () => {
+        expect(matchesDateFormat(format, value)).to.eq(false);
+      });
+    });
+
+    it('does not check values for a DateTimeFormatter pattern', () => {
+      expect(
+        matchesDateFormat(
+          "yyyy-MM-dd'T'HH:mm:ss.SSSxxx'['VV']'",
+          '2020-12-01T10:00:00.000+11:00[Australia/Melbourne]'
+        )
+      ).to.eq(true);
+    });
+  });
+
+  describe('#validateDateFormat', () => {
+    it('throws an error for unquoted text', () => {
+      expect(() => validateDateFormat('yyyy-MM-ddTHH:mm')).to.throw(
+        /illegal pattern character 'T'/
+      );
+    });
+
+    it('throws an error for an unterminated quote', () => {
+      expect(() => validateDateFormat("yyyy-MM-dd'T")).to.throw(
+        /unterminated quote/
+      );
+    });
+
+    it('throws an error for an invalid ISO 8601 time zone', () => {
+      expect(() => validateDateFormat('HH:mm XXXXXX')).to.throw(
+        /invalid ISO 8601 time zone/
+      );
+    });
+
+    it('accepts DateTimeFormatter patterns', () => {
+      expect(() =>
+        validateDateFormat("uuuu-MM-dd'T'HH:mm:ss.nnnnnnnnnOOOO'['VV']' e")
+      ).not.to.throw();
+    });
+  });
+});
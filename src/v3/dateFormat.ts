@@ -0,0 +1,249 @@
+import MatcherError from '../errors/matcherError';
+import { escapeRegex } from './regex';
+
+// Checks values against Java SimpleDateFormat patterns (the format used by the
+// date, time and datetime matchers), so that invalid examples are reported
+// when the matcher is created rather than by the mock server. The core also
+// accepts Java DateTimeFormatter patterns; values for a format that uses
+// letters only DateTimeFormatter has are not checked.
+
+const PATTERN_LETTERS = 'GyYMLwWDdFEuaHkKhmsSzZX';
+const DATE_TIME_FORMATTER_LETTERS = 'QqecAnNVvOxp';
+const NUMERIC_LETTERS = 'yYwWDdFuHkKhmsS';
+
+const MONTHS = [
+  'january',
+  'february',
+  'march',
+  'april',
+  'may',
+  'june',
+  'july',
+  'august',
+  'september',
+  'october',
+  'november',
+  'december',
+];
+const DAYS = [
+  'monday',
+  'tuesday',
+  'wednesday',
+  'thursday',
+  'friday',
+  'saturday',
+  'sunday',
+];
+
+const RANGES: Record<string, [number, number]> = {
+  M: [1, 12],
+  L: [1, 12],
+  w: [1, 53],
+  W: [0, 6],
+  D: [1, 366],
+  d: [1, 31],
+  F: [1, 5],
+  u: [1, 7],
+  H: [0, 23],
+  k: [1, 24],
+  K: [0, 11],
+  h: [1, 12],
+  m: [0, 59],
+  s: [0, 59],
+};
+
+const GENERAL_TIME_ZONE =
+  '(?:GMT|UTC)(?:[+-]\\d{1,2}(?::?\\d{2})?)?|[A-Z][A-Za-z]*(?: [A-Z][A-Za-z]*)*';
+const RFC822_TIME_ZONE = '[+-]\\d{4}';
+const ISO8601_TIME_ZONES = [
+  'Z|[+-]\\d{2}',
+  'Z|[+-]\\d{4}',
+  'Z|[+-]\\d{2}:\\d{2}',
+];
+
+// Quoted text ('' is a single quote), runs of a pattern letter, or literals
+const TOKEN_REGEX = /''|'(?:[^']|'')+'|([A-Za-z])\1*|[^A-Za-z']+/g;
+
+interface DateField {
+  letter: string;
+  count: number;
+}
+
+type DateFormatToken = string | DateField;
+
+const isNumeric = (token: DateFormatToken | undefined): boolean =>
+  token !== undefined &&
+  typeof token !== 'string' &&
+  (NUMERIC_LETTERS.includes(token.letter) ||
+    ('ML'.includes(token.letter) && token.count <= 2));
+
+const tokenise = (format: string): DateFormatToken[] => {
+  const parts = format.match(TOKEN_REGEX) || [];
+
+  if (parts.join('') !== format) {
+    throw new MatcherError(
+      `The date format '${format}' has an unterminated quote`
+    );
+  }
+
+  return parts.map((part) => {
+    if (part === "''") {
+      return "'";
+    }
+    if (part.startsWith("'")) {
+      return part.slice(1, -1).replace(/''/g, "'");
+    }
+    if (!/^[A-Za-z]/.test(part)) {
+      return part;
+    }
+    if (
+      !PATTERN_LETTERS.includes(part[0]) &&
+      !DATE_TIME_FORMATTER_LETTERS.includes(part[0])
+    ) {
+      throw new MatcherError(
+        `The date format '${format}' has an illegal pattern character '${part[0]}'. Text must be quoted, e.g. 'T'`
+      );
+    }
+    if (part[0] === 'X' && part.length > 5) {
+      throw new MatcherError(
+        `The date format '${format}' has an invalid ISO 8601 time zone '${part}'`
+      );
+    }
+    return { letter: part[0], count: part.length };
+  });
+};
+
+// Whether values for the format can be checked, i.e. it only uses the
+// SimpleDateFormat letters
+const isCheckable = (tokens: DateFormatToken[]): boolean =>
+  tokens.every(
+    (token) =>
+      typeof token === 'string' ||
+      (PATTERN_LETTERS.includes(token.letter) &&
+        !(token.letter === 'X' && token.count > 3))
+  );
+
+const fieldRegex = (field: DateField, next?: DateFormatToken): string => {
+  if (isNumeric(field)) {
+    // Abutting numeric fields (e.g. 'yyyyMMdd') use the number of pattern
+    // letters as the width, otherwise any number of digits is accepted
+    return isNumeric(next) ? `(\\d{${field.count}})` : '(\\d+)';
+  }
+
+  switch (field.letter) {
+    case 'z':
+    case 'Z':
+      return `(${RFC822_TIME_ZONE}|${GENERAL_TIME_ZONE})`;
+    case 'X':
+      return `(${ISO8601_TIME_ZONES[field.count - 1]})`;
+    default:
+      // Month and day names, AM/PM and eras are checked after matching
+      return '([A-Za-z]+)';
+  }
+};
+
+// Both the full and abbreviated names are accepted when parsing, regardless
+// of the number of pattern letters
+const nameIndex = (names: string[], text: string): number => {
+  const lower = text.toLowerCase();
+  return names.findIndex(
+    (name) => name === lower || name.slice(0, 3) === lower
+  );
+};
+
+const daysInMonth = (month: number, year?: number): number => {
+  if (month === 2) {
+    const leap =
+      year === undefined ||
+      (year % 4 === 0 && (year % 100 !== 0 || year % 400 === 0));
+    return leap ? 29 : 28;
+  }
+  return [4, 6, 9, 11].includes(month) ? 30 : 31;
+};
+
+const validField = (
+  field: DateField,
+  text: string,
+  values: Record<string, number>
+): boolean => {
+  if (isNumeric(field)) {
+    const range = RANGES[field.letter];
+    const value = parseInt(text, 10);
+
+    Object.assign(values, { [field.letter]: value });
+    return !range || (value >= range[0] && value <= range[1]);
+  }
+
+  switch (field.letter) {
+    case 'M':
+    case 'L': {
+      const month = nameIndex(MONTHS, text);
+
+      Object.assign(values, { M: month + 1 });
+      return month >= 0;
+    }
+    case 'E':
+      return nameIndex(DAYS, text) >= 0;
+    case 'a':
+      return ['am', 'pm'].includes(text.toLowerCase());
+    case 'G':
+      return ['ad', 'bc'].includes(text.toLowerCase());
+    default:
+      return true;
+  }
+};
+
+/**
+ * Checks that the format is a valid Java SimpleDateFormat or DateTimeFormatter
+ * pattern, throwing a MatcherError if it isn't
+ * @param format Date format string. See [Java SimpleDateFormat](https://docs.oracle.com/javase/8/docs/api/java/text/SimpleDateFormat.html)
+ */
+export function validateDateFormat(format: string): void {
+  tokenise(format);
+}
+
+/**
+ * Returns true if the value can be parsed with the Java SimpleDateFormat
+ * pattern, including checking that each field is in range (e.g. the day exists
+ * in the month). Values for DateTimeFormatter patterns are always accepted
+ * @param format Date format string. See [Java SimpleDateFormat](https://docs.oracle.com/javase/8/docs/api/java/text/SimpleDateFormat.html)
+ * @param value Value to parse
+ */
+export function matchesDateFormat(format: string, value: string): boolean {
+  const tokens = tokenise(format);
+  if (!isCheckable(tokens)) {
+    return true;
+  }
+
+  const fields = tokens.filter(
+    (token): token is DateField => typeof token !== 'string'
+  );
+  const pattern = tokens
+    .map((token, i) =>
+      typeof token === 'string'
+        ? escapeRegex(token)
+        : fieldRegex(token, tokens[i + 1])
+    )
+    .join('');
+  const match = new RegExp(`^${pattern}$`).exec(value);
+
+  if (!match) {
+    return false;
+  }
+
+  const values: Record<string, number> = {};
+  if (!fields.every((field, i) => validField(field, match[i + 1], values))) {
+    return false;
+  }
+
+  const year = fields.some((f) => f.letter === 'y' && f.count !== 2)
+    ? values.y
+    : undefined;
+  const month = values.M ?? values.L;
+
+  return (
+    month === undefined ||
+    values.d === undefined ||
+    values.d <= daysInMonth(month, year)
+  );
+}
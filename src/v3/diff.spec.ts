@@ -0,0 +1,163 @@
+import chai from 'chai';
+import { diffOptions, renderDiff, templateAt, truncate } from './diff';
+import { eachLike, integer, like } from './matchers';
+
+const { expect } = chai;
+
+describe('Diff', () => {
+  const template = {
+    id: integer(1),
+    name: like('Fred'),
+    tags: ['a'],
+    status: 'open',
+  };
+  const actual = { id: 'x', name: 'Mary', tags: ['a'], status: 'closed' };
+
+  describe('#renderDiff', () => {
+    it('renders a unified diff, annotating the matchers in the template', () => {
+      expect(
+        renderDiff(template, actual, { colour: false, highlight: ['$.id'] })
+      ).to.eq(
+        [
+          '- Expected',
+          '+ Actual',
+          '',
+          '  {',
+          '-!  "id": 1,  // integer',
+          '+!  "id": "x",',
+          '    "name": "Fred",  // type',
+          '    "tags": [',
+          '      "a"',
+          '    ],',
+          '-   "status": "open"',
+          '+   "status": "closed"',
+          '  }',
+        ].join('\n')
+      );
+    });
+
+    it('renders a side-by-side diff', () => {
+      expect(
+        renderDiff(template, actual, {
+          colour: false,
+          format: 'side-by-side',
+          highlight: ['$.id'],
+          width: 24,
+        })
+      ).to.eq(
+        [
+          '  Expected                   Actual',
+          '',
+          '  {                          {',
+          '!   "id": 1,  // integer   |   "id": "x",',
+          '    "name": "Fred",  //...     "name": "Mary",',
+          '    "tags": [                  "tags": [',
+          '      "a"                        "a"',
+          '    ],                         ],',
+          '    "status": "open"       |   "status": "closed"',
+          '  }                          }',
+        ].join('\n')
+      );
+    });
+
+    it('matches every item of an array matcher against its template', () => {
+      const diff = renderDiff(
+        { items: eachLike({ id: integer(1) }) },
+        { items: [{ id: 1 }, { id: 2 }] },
+        { colour: false }
+      );
+
+      expect(
+        diff
+          .split('\n')
+          .slice(3)
+          .filter((line) => /^[-+]/.test(line))
+      ).to.deep.eq([]);
+    });
+
+    it('only shows the lines around a difference', () => {
+      const diff = renderDiff(
+        { a: 1, b: 2, c: 3, d: 4, e: 5, f: 6, g: 7 },
+        { a: 1, b: 2, c: 3, d: 4, e: 5, f: 6, g: 8 },
+        { colour: false, context: 1 }
+      );
+
+      expect(diff.split('\n').slice(3)).to.deep.eq([
+        '  ...',
+        '    "f": 6,',
+        '-   "g": 7',
+        '+   "g": 8',
+        '  }',
+      ]);
+    });
+
+    it('truncates a diff to the maximum number of lines', () => {
+      const diff = renderDiff(
+        { a: [1, 2, 3] },
+        { a: [1, 2, 4] },
+        { colour: false, maxLines: 4 }
+      );
+
+      expect(diff.split('\n')).to.have.lengthOf(5);
+      expect(diff).to.contain('... 7 more lines');
+    });
+
+    it('diffs large bodies', () => {
+      const expected = Array.from({ length: 20000 }, (_, i) => i);
+      const changed = expected.map((i) => (i === 10000 ? -1 : i));
+
+      const diff = renderDiff(expected, changed, { colour: false });
+
+      expect(diff).to.contain('-   10000,\n+   -1,');
+      expect(diff.split('\n')).to.have.lengthOf.below(20);
+    });
+  });
+
+  describe('#templateAt', () => {
+    it('finds the template of a path in the body', () => {
+      expect(templateAt(template, '$.id')).to.deep.eq(integer(1));
+      expect(templateAt(template, "$['name']")).to.deep.eq(like('Fred'));
+    });
+
+    it('finds the template of each item of an array matcher', () => {
+      const items = { items: eachLike({ id: integer(1) }) };
+
+      expect(templateAt(items, '$.items[2]')).to.deep.eq({ id: integer(1) });
+      expect(templateAt(items, '$.items[2].id')).to.deep.eq(integer(1));
+    });
+
+    it('returns undefined for a path that is not in the template', () => {
+      expect(templateAt(template, '$.missing.id')).to.eq(undefined);
+    });
+  });
+
+  describe('#diffOptions', () => {
+    const env = { ...process.env };
+
+    afterEach(() => {
+      process.env = { ...env };
+    });
+
+    it('reads options that are not given from the environment', () => {
+      process.env.PACT_DIFF_FORMAT = 'side-by-side';
+      process.env.PACT_DIFF_MAX_LINES = '10';
+      process.env.NO_COLOR = '1';
+
+      expect(diffOptions({ maxLines: 20 })).to.deep.eq({
+        format: 'side-by-side',
+        colour: false,
+        context: 3,
+        maxLines: 20,
+        width: 60,
+        maxBodyLength: 500,
+      });
+    });
+  });
+
+  describe('#truncate', () => {
+    it('shortens text longer than the maximum length', () => {
+      expect(truncate('abcdef', 3)).to.eq('abc...');
+      expect(truncate('abc', 3)).to.eq('abc');
+    });
+  });
+});
@@ -0,0 +1,653 @@
+/**
+ * Renders a diff of an expected body (or template) against an actual body,
+ * used to explain body mismatches from the mock server.
+ * @module diff
+ */
+import clc from 'cli-color';
+import { diffArrays } from 'diff';
+import { flatten } from 'ramda';
+import { propertyPath } from './matchers';
+import { CombinedMatcher, Matcher } from './types';
+
+export interface DiffOptions {
+  /**
+   * Show the expected and actual bodies as a `unified` diff (the default), or
+   * `side-by-side`. Can also be set with PACT_DIFF_FORMAT
+   */
+  format?: 'unified' | 'side-by-side';
+  /**
+   * Colour the diff. Defaults to true when writing to a terminal, and can be
+   * set with PACT_DIFF_COLOUR (or turned off with NO_COLOR)
+   */
+  colour?: boolean;
+  /**
+   * Number of unchanged lines to show around each difference. Defaults to 3,
+   * and can be set with PACT_DIFF_CONTEXT
+   */
+  context?: number;
+  /**
+   * Maximum number of lines of a diff to show. Defaults to 100, and can be
+   * set with PACT_DIFF_MAX_LINES
+   */
+  maxLines?: number;
+  /**
+   * Width of each column of a side-by-side diff. Defaults to 60, and can be
+   * set with PACT_DIFF_WIDTH
+   */
+  width?: number;
+  /**
+   * Maximum number of characters of a request body to show. Defaults to 500,
+   * and can be set with PACT_DIFF_MAX_BODY_LENGTH
+   */
+  maxBodyLength?: number;
+}
+
+export interface RenderDiffOptions extends DiffOptions {
+  /**
+   * Paths that failed to match (e.g. `$.items[1].id`), which are highlighted
+   */
+  highlight?: string[];
+  /**
+   * Path of the bodies in the document, when diffing part of a body. Defaults
+   * to the root (`$`)
+   */
+  path?: string;
+}
+
+const envNumber = (name: string): number | undefined => {
+  const value = parseInt(process.env[name] || '', 10);
+
+  return Number.isNaN(value) ? undefined : value;
+};
+
+const envColour = (): boolean | undefined => {
+  if (process.env.NO_COLOR) {
+    return false;
+  }
+  if (process.env.PACT_DIFF_COLOUR) {
+    return process.env.PACT_DIFF_COLOUR !== 'false';
+  }
+  return undefined;
+};
+
+const envFormat = (): DiffOptions['format'] => {
+  const format = process.env.PACT_DIFF_FORMAT;
+
+  return format === 'unified' || format === 'side-by-side' ? format : undefined;
+};
+
+/**
+ * Resolves the diff options, using the environment variables and then the
+ * defaults for any that are not given
+ */
+export const diffOptions = (
+  options: DiffOptions = {}
+): Required<DiffOptions> => ({
+  format: options.format ?? envFormat() ?? 'unified',
+  colour: options.colour ?? envColour() ?? Boolean(process.stdout.isTTY),
+  context: options.context ?? envNumber('PACT_DIFF_CONTEXT') ?? 3,
+  maxLines: options.maxLines ?? envNumber('PACT_DIFF_MAX_LINES') ?? 100,
+  width: options.width ?? envNumber('PACT_DIFF_WIDTH') ?? 60,
+  maxBodyLength:
+    options.maxBodyLength ?? envNumber('PACT_DIFF_MAX_BODY_LENGTH') ?? 500,
+});
+
+/**
+ * Shortens the text to the maximum length, marking where it was cut
+ */
+export const truncate = (text: string, maxLength: number): string =>
+  text.length > maxLength ? `${text.substr(0, maxLength)}...` : text;
+
+// Paths are compared by their parts (`$`, `.key`, `['key']` or `[0]`). In the
+// paths of a template, `[*]` matches any item of an array matcher and `.*` any
+// key of an eachKey or eachValue matcher
+const PATH_PARTS = /^\$|\.[^.[]+|\[(?:'(?:[^'\\]|\\.)*'|[^\]]*)\]/g;
+const INDEX = /^\[\d+\]$/;
+
+const pathParts = (path: string): string[] => path.match(PATH_PARTS) || [];
+
+const partMatches = (pattern: string, part: string): boolean =>
+  pattern === part ||
+  (pattern === '[*]' && INDEX.test(part)) ||
+  (pattern === '.*' && !INDEX.test(part));
+
+// True if the path is the same as, or within, the other
+const pathWithin = (path: string, parent: string): boolean => {
+  const parts = pathParts(path);
+  const parentParts = pathParts(parent);
+
+  return (
+    parts.length >= parentParts.length &&
+    parentParts.every((p, i) => partMatches(parts[i], p))
+  );
+};
+
+// The key of a `.key` or `['key']` part of a path
+const keyOf = (part: string): string =>
+  part.startsWith('.')
+    ? part.slice(1)
+    : part.slice(2, -2).replace(/\\'/g, "'");
+
+const isTemplateMatcher = (
+  x: unknown
+): x is Matcher<unknown> | CombinedMatcher<unknown> =>
+  x !== null && typeof x === 'object' && 'pact:matcher:type' in x;
+
+/**
+ * Finds the part of a template at a path of the body, e.g. the template of an
+ * `eachLike` for `$.items[2]`. Returns undefined if the path is not in the
+ * template.
+ * @param template Body template
+ * @param path Path of the value in the body
+ */
+export const templateAt = (template: unknown, path: string): unknown => {
+  const [root, ...parts] = pathParts(path);
+  if (root !== '$') {
+    return undefined;
+  }
+
+  return parts.reduce<unknown>((node, part) => {
+    let value = node;
+    let items = false;
+    while (isTemplateMatcher(value)) {
+      items = items || Array.isArray(value.value);
+      value = value.value;
+    }
+    if (value === null || typeof value !== 'object') {
+      return undefined;
+    }
+    if (Array.isArray(value)) {
+      if (!INDEX.test(part)) {
+        return undefined;
+      }
+      const index = parseInt(part.slice(1, -1), 10);
+      // Each item of an array matcher is matched against its template
+      return value[items ? Math.min(index, value.length - 1) : index];
+    }
+
+    return (value as Record<string, unknown>)[keyOf(part)];
+  }, template);
+};
+
+const setAt = (
+  node: unknown,
+  [part, ...rest]: string[],
+  value: unknown
+): unknown => {
+  if (part === undefined) {
+    return value;
+  }
+  if (INDEX.test(part)) {
+    const items = Array.isArray(node) ? node : [];
+    const index = parseInt(part.slice(1, -1), 10);
+    if (index > items.length) {
+      return node;
+    }
+    const copy = [...items];
+    copy[index] = setAt(items[index], rest, value);
+    return copy;
+  }
+
+  const object =
+    node !== null && typeof node === 'object' && !Array.isArray(node)
+      ? (node as Record<string, unknown>)
+      : {};
+  const key = keyOf(part);
+  return { ...object, [key]: setAt(object[key], rest, value) };
+};
+
+/**
+ * Returns a copy of the body with the values at the given paths replaced,
+ * adding any objects and arrays on the way. Shallower paths are replaced
+ * first, so the values of deeper paths are put in them. Array items past the
+ * end of an array are left out.
+ * @param body Body to replace the values in
+ * @param values Values to put in the body, and the paths to put them at
+ */
+export const withValuesAt = (
+  body: unknown,
+  values: { path: string; value: unknown }[]
+): unknown =>
+  [...values]
+    .map((v) => ({ ...v, parts: pathParts(v.path) }))
+    .filter(({ parts }) => parts[0] === '$')
+    .sort((a, b) => a.parts.length - b.parts.length)
+    .reduce(
+      (acc, { parts, value }) => setAt(acc, parts.slice(1), value),
+      body
+    );
+
+const pathMatches = (pattern: string, path: string): boolean =>
+  pathParts(pattern).length === pathParts(path).length &&
+  pathWithin(pattern, path);
+
+type LineKind = 'open' | 'close' | 'value';
+
+interface JsonLine {
+  path: string;
+  pattern: string;
+  kind: LineKind;
+  text: string;
+  note?: string;
+  // Lines within a matcher only need to line up with the actual body, as the
+  // example value doesn't need to be equal to the actual one
+  matched: boolean;
+}
+
+interface Position {
+  path: string;
+  pattern: string;
+  depth: number;
+  key?: string;
+  last: boolean;
+  note?: string;
+  matched: boolean;
+  // Matches the items (or keys) of the value with any item (or key)
+  wildcard?: 'item' | 'key';
+}
+
+const describeMatcher = (
+  matcher: Matcher<unknown> | CombinedMatcher<unknown>
+): string => {
+  const type = matcher['pact:matcher:type'];
+  if (!Array.isArray(type)) {
+    return type;
+  }
+  const names = type.map((m) => m['pact:matcher:type']).join(', ');
+
+  return `allOf(${names})`;
+};
+
+const jsonLines = (value: unknown, at: Position): JsonLine[] => {
+  if (isTemplateMatcher(value)) {
+    const type = value['pact:matcher:type'];
+    const { variants } = value as { variants?: unknown[] };
+    let wildcard: Position['wildcard'];
+    if (type === 'type' && Array.isArray(value.value)) {
+      wildcard = 'item';
+    } else if (
+      typeof type === 'string' &&
+      ['values', 'eachKey', 'eachValue'].includes(type)
+    ) {
+      wildcard = 'key';
+    }
+
+    return jsonLines(variants ?? value.value, {
+      ...at,
+      note: [at.note, describeMatcher(value)].filter(Boolean).join(', '),
+      matched: true,
+      wildcard: variants ? 'item' : wildcard,
+    });
+  }
+
+  const indent = '  '.repeat(at.depth);
+  const prefix = at.key === undefined ? '' : `${JSON.stringify(at.key)}: `;
+  const comma = at.last ? '' : ',';
+  const line = (kind: LineKind, text: string, note?: string): JsonLine => ({
+    path: at.path,
+    pattern: at.pattern,
+    kind,
+    text: `${indent}${text}`,
+    note,
+    matched: at.matched,
+  });
+
+  if (value === null || typeof value !== 'object') {
+    return [
+      line('value', `${prefix}${JSON.stringify(value)}${comma}`, at.note),
+    ];
+  }
+
+  const [open, close] = Array.isArray(value) ? ['[', ']'] : ['{', '}'];
+  const entries: Position[] = Array.isArray(value)
+    ? value.map((_, i) => ({
+        path: `${at.path}[${i}]`,
+        pattern: `${at.pattern}${at.wildcard === 'item' ? '[*]' : `[${i}]`}`,
+        depth: at.depth + 1,
+        last: i === value.length - 1,
+        matched: at.matched,
+      }))
+    : Object.keys(value).map((key, i, keys) => ({
+        path: propertyPath(at.path, key),
+        pattern:
+          at.wildcard === 'key'
+            ? `${at.pattern}.*`
+            : propertyPath(at.pattern, key),
+        depth: at.depth + 1,
+        key,
+        last: i === keys.length - 1,
+        matched: at.matched,
+      }));
+  const values = Array.isArray(value) ? value : Object.values(value);
+
+  if (entries.length === 0) {
+    return [line('value', `${prefix}${open}${close}${comma}`, at.note)];
+  }
+
+  return [
+    line('open', `${prefix}${open}`, at.note),
+    ...flatten(entries.map((entry, i) => jsonLines(values[i], entry))),
+    line('close', `${close}${comma}`),
+  ];
+};
+
+interface Same {
+  type: 'same';
+  expected?: JsonLine;
+  actual?: JsonLine;
+}
+
+interface Removed {
+  type: 'removed';
+  expected: JsonLine;
+}
+
+interface Added {
+  type: 'added';
+  actual: JsonLine;
+}
+
+type DiffOp = Same | Removed | Added;
+
+// The line of the actual body where there is one, as its path has the actual
+// index of any array items
+const lineOf = (op: DiffOp): JsonLine => {
+  if (op.type === 'removed') {
+    return op.expected;
+  }
+  if (op.type === 'added') {
+    return op.actual;
+  }
+  return (op.actual ?? op.expected) as JsonLine;
+};
+
+const withoutComma = (text: string): string => text.replace(/,$/, '');
+
+const sameLine = (expected: JsonLine, actual: JsonLine): boolean =>
+  expected.kind === actual.kind &&
+  (expected.matched
+    ? pathMatches(expected.pattern, actual.path)
+    : expected.path === actual.path &&
+      withoutComma(expected.text) === withoutComma(actual.text));
+
+// Lines of the actual body that line up with the template of an array (or
+// eachKey) matcher, e.g. the second and later items of an `eachLike`
+const matchedByWildcard = (expected: JsonLine[], actual: JsonLine): boolean =>
+  expected.some(
+    (e) => e.matched && e.pattern.includes('*') && sameLine(e, actual)
+  );
+
+// A run of lines from `diffArrays`, which are all added, removed or the same
+interface Change {
+  count?: number;
+  value: JsonLine[];
+  added?: boolean;
+  removed?: boolean;
+}
+
+// Diffs the lines, comparing lines within a matcher by their paths. Removed
+// lines are listed before added ones in each change, and added lines that line
+// up with the template of an array matcher are the same
+const diffLines = (expected: JsonLine[], actual: JsonLine[]): DiffOp[] => {
+  const ops: DiffOp[] = [];
+  let e = 0;
+  let a = 0;
+
+  const changes: Change[] = diffArrays(expected, actual, {
+    comparator: sameLine,
+  });
+  changes.forEach((change) => {
+    for (let i = 0; i < (change.count ?? change.value.length); i += 1) {
+      if (change.removed) {
+        ops.push({ type: 'removed', expected: expected[e] });
+        e += 1;
+      } else if (change.added) {
+        ops.push({ type: 'added', actual: actual[a] });
+        a += 1;
+      } else {
+        ops.push({ type: 'same', expected: expected[e], actual: actual[a] });
+        e += 1;
+        a += 1;
+      }
+    }
+  });
+
+  // Lists the removed lines of each change before the added ones
+  const ordered: DiffOp[] = [];
+  let added: Added[] = [];
+  ops.forEach((op) => {
+    if (op.type === 'added') {
+      added.push(op);
+      return;
+    }
+    if (op.type === 'same') {
+      ordered.push(...added);
+      added = [];
+    }
+    ordered.push(op);
+  });
+  ordered.push(...added);
+
+  return ordered.map((op) =>
+    op.type === 'added' && matchedByWildcard(expected, op.actual)
+      ? { type: 'same', actual: op.actual }
+      : op
+  );
+};
+
+// Drops unchanged lines that are more than `context` lines from a difference
+const withContext = (
+  ops: DiffOp[],
+  changed: (op: DiffOp) => boolean,
+  context: number
+): (DiffOp | undefined)[] => {
+  const changes = ops.reduce<number[]>(
+    (acc, op, i) => (changed(op) ? [...acc, i] : acc),
+    []
+  );
+  if (changes.length === 0) {
+    return ops;
+  }
+
+  return ops.reduce<(DiffOp | undefined)[]>((acc, op, i) => {
+    if (changes.some((c) => Math.abs(c - i) <= context)) {
+      return [...acc, op];
+    }
+    // A single undefined marks each run of lines that were left out
+    return acc[acc.length - 1] === undefined && acc.length > 0
+      ? acc
+      : [...acc, undefined];
+  }, []);
+};
+
+interface Painter {
+  removed(text: string): string;
+  added(text: string): string;
+  note(text: string): string;
+  highlight(text: string): string;
+}
+
+const painter = (colour: boolean): Painter =>
+  colour
+    ? {
+        removed: (text) => clc.red(text),
+        added: (text) => clc.green(text),
+        note: (text) => clc.blackBright(text),
+        highlight: (text) => clc.bold.yellow(text),
+      }
+    : {
+        removed: (text) => text,
+        added: (text) => text,
+        note: (text) => text,
+        highlight: (text) => text,
+      };
+
+const noteFor = (line: JsonLine, paint: Painter): string =>
+  line.note ? `  ${paint.note(`// ${line.note}`)}` : '';
+
+const renderUnified = (
+  ops: (DiffOp | undefined)[],
+  isHighlighted: (op: DiffOp) => boolean,
+  paint: Painter
+): string[] => {
+  const removed = (line: JsonLine, mark: string): string =>
+    `${paint.removed(`-${mark}${line.text}`)}${noteFor(line, paint)}`;
+  const added = (line: JsonLine, mark: string): string =>
+    paint.added(`+${mark}${line.text}`);
+
+  const render = (op: DiffOp | undefined): string[] => {
+    if (!op) {
+      return [paint.note('  ...')];
+    }
+    const mark = isHighlighted(op) ? '!' : ' ';
+    if (op.type === 'removed') {
+      return [removed(op.expected, mark)];
+    }
+    if (op.type === 'added') {
+      return [added(op.actual, mark)];
+    }
+    const { expected, actual } = op;
+    // A line that failed to match shows both the template and actual value
+    if (mark === '!' && expected && actual && expected.text !== actual.text) {
+      return [removed(expected, mark), added(actual, mark)];
+    }
+    // Otherwise the template is shown, with any matcher annotations (and
+    // the comma from the actual body, where there are more items)
+    const line = (expected ?? actual) as JsonLine;
+    const comma = actual?.text.endsWith(',') ? ',' : '';
+    const text = ` ${mark}${withoutComma(line.text)}${comma}`;
+
+    return [
+      `${mark === '!' ? paint.highlight(text) : text}${noteFor(line, paint)}`,
+    ];
+  };
+
+  return [
+    paint.removed('- Expected'),
+    paint.added('+ Actual'),
+    '',
+    ...flatten(ops.map(render)),
+  ];
+};
+
+const fit = (text: string, width: number): string =>
+  text.length > width
+    ? `${text.substr(0, Math.max(width - 3, 0))}...`
+    : text.padEnd(width);
+
+const renderSideBySide = (
+  ops: (DiffOp | undefined)[],
+  isHighlighted: (op: DiffOp) => boolean,
+  paint: Painter,
+  width: number
+): string[] => {
+  const cell = (line?: JsonLine): string =>
+    fit(
+      line ? `${line.text}${line.note ? `  // ${line.note}` : ''}` : '',
+      width
+    );
+  const row = (mark: string, left: string, marker: string, right: string) =>
+    `${mark} ${left} ${marker} ${right}`.trimEnd();
+
+  const rows = [row(' ', fit('Expected', width), ' ', 'Actual'), ''];
+  let removed: Removed[] = [];
+  let added: Added[] = [];
+
+  // Pairs up the removed and added lines, so changed values sit side by side
+  const flush = () => {
+    for (let i = 0; i < Math.max(removed.length, added.length); i += 1) {
+      const left = removed[i];
+      const right = added[i];
+      let marker = '|';
+      if (!right) {
+        marker = '<';
+      } else if (!left) {
+        marker = '>';
+      }
+      const highlighted =
+        (left && isHighlighted(left)) || (right && isHighlighted(right));
+      rows.push(
+        row(
+          highlighted ? '!' : ' ',
+          paint.removed(cell(left?.expected)),
+          marker,
+          paint.added(cell(right?.actual))
+        )
+      );
+    }
+    removed = [];
+    added = [];
+  };
+
+  ops.forEach((op) => {
+    if (op?.type === 'removed') {
+      removed.push(op);
+    } else if (op?.type === 'added') {
+      added.push(op);
+    } else {
+      flush();
+      if (!op) {
+        rows.push(paint.note(row(' ', fit('...', width), ' ', '...')));
+      } else if (isHighlighted(op)) {
+        rows.push(
+          paint.highlight(row('!', cell(op.expected), '|', cell(op.actual)))
+        );
+      } else {
+        rows.push(row(' ', cell(op.expected), ' ', cell(op.actual)));
+      }
+    }
+  });
+  flush();
+
+  return rows;
+};
+
+/**
+ * Renders a diff of the expected body against the actual body. The expected
+ * body may be a template, in which case each matcher is shown as its example
+ * value, annotated with the type of the matcher. Lines within a matcher only
+ * differ if they are missing from the actual body (or vice versa), and any
+ * paths given in `highlight` are marked with `!`.
+ * @param expected Expected body or template
+ * @param actual Actual body
+ * @param options Format and limits of the diff, and the paths to highlight
+ */
+export const renderDiff = (
+  expected: unknown,
+  actual: unknown,
+  options: RenderDiffOptions = {}
+): string => {
+  const { format, colour, context, maxLines, width } = diffOptions(options);
+  const root = options.path ?? '$';
+  const highlight = options.highlight ?? [];
+  const at = { path: root, pattern: root, depth: 0, last: true };
+  const ops = diffLines(
+    jsonLines(expected, { ...at, matched: false }),
+    jsonLines(actual, { ...at, matched: false })
+  );
+
+  const isHighlighted = (op: DiffOp): boolean => {
+    const { pattern } = lineOf(op);
+    return highlight.some((path) => pathWithin(pattern, path));
+  };
+  const changed = (op: DiffOp): boolean =>
+    op.type !== 'same' || isHighlighted(op);
+
+  const paint = painter(colour);
+  const visible = withContext(ops, changed, context);
+  const lines =
+    format === 'side-by-side'
+      ? renderSideBySide(visible, isHighlighted, paint, width)
+      : renderUnified(visible, isHighlighted, paint);
+
+  if (lines.length <= maxLines) {
+    return lines.join('\n');
+  }
+
+  return [
+    ...lines.slice(0, maxLines),
+    paint.note(
+      `... ${lines.length - maxLines} more lines (set PACT_DIFF_MAX_LINES to show more)`
+    ),
+  ].join('\n');
+};
@@ -0,0 +1,362 @@
+import chai from 'chai';
+import {
+  MatchingResult,
+  Mismatch,
+  RequestMismatch,
+} from '@pact-foundation/pact-core';
+import {
+  bodyDiff,
+  closestRequest,
+  contractMismatches,
+  displayRequest,
+  generateMockServerError,
+  isExpectedRequest,
+} from './display';
+import ContractMismatchError from '../errors/contractMismatchError';
+import { eachLike, integer } from './matchers';
+
+const { expect } = chai;
+
+const results = [
+  {
+    type: 'request-mismatch',
+    method: 'POST',
+    path: '/orders',
+    mismatches: [
+      {
+        type: 'BodyMismatch',
+        path: '$.items[0].id',
+        expected: '1',
+        actual: '"a"',
+        mismatch: "Expected 'a' (String) to be the same type as 1 (Integer)",
+      },
+      {
+        type: 'HeaderMismatch',
+        key: 'Accept',
+        expected: 'application/json',
+        actual: 'text/plain',
+        mismatch:
+          "Mismatch with header 'Accept': Expected 'text/plain' to be equal to 'application/json'",
+      },
+    ],
+  },
+  {
+    type: 'missing-request',
+    method: 'GET',
+    path: '/orders/1',
+    request: { method: 'GET', path: '/orders/1' },
+  },
+] as unknown as MatchingResult[];
+
+describe('Mock server mismatches', () => {
+  describe('#contractMismatches', () => {
+    it('returns a mismatch for each problem with each request', () => {
+      expect(contractMismatches(results)).to.deep.eq([
+        {
+          kind: 'request-mismatch',
+          request: { method: 'POST', path: '/orders' },
+          type: 'BodyMismatch',
+          path: '$.items[0].id',
+          expected: '1',
+          actual: '"a"',
+          mismatch: "Expected 'a' (String) to be the same type as 1 (Integer)",
+        },
+        {
+          kind: 'request-mismatch',
+          request: { method: 'POST', path: '/orders' },
+          type: 'HeaderMismatch',
+          path: 'Accept',
+          expected: 'application/json',
+          actual: 'text/plain',
+          mismatch:
+            "Mismatch with header 'Accept': Expected 'text/plain' to be equal to 'application/json'",
+        },
+        {
+          kind: 'missing-request',
+          request: { method: 'GET', path: '/orders/1' },
+          expected: { method: 'GET', path: '/orders/1' },
+          mismatch: 'The request was expected but not received',
+        },
+      ]);
+    });
+
+    it('gives the type of the matcher at the path of the body template', () => {
+      const [mismatch] = contractMismatches(
+        results.slice(0, 1),
+        { colour: false },
+        [
+          {
+            method: 'POST',
+            path: '/orders',
+            body: { items: eachLike({ id: integer(1) }) },
+          },
+        ]
+      );
+
+      expect(mismatch.matcherType).to.eq('integer');
+    });
+
+    it('ignores successful requests', () => {
+      expect(
+        contractMismatches([
+          { type: 'request-match' },
+        ] as unknown as MatchingResult[])
+      ).to.deep.eq([]);
+    });
+  });
+
+  describe('#bodyDiff', () => {
+    it('renders a diff of the whole body, highlighting the mismatches', () => {
+      const mismatches = [
+        {
+          type: 'BodyMismatch',
+          path: '$.item.id',
+          expected: '1',
+          actual: '2',
+          mismatch: 'Expected 2 to be equal to 1',
+        },
+      ] as unknown as Mismatch[];
+
+      expect(bodyDiff(mismatches, { colour: false })).to.eq(
+        [
+          '- Expected',
+          '+ Actual',
+          '',
+          '  {',
+          '    "item": {',
+          '-!    "id": 1',
+          '+!    "id": 2',
+          '    }',
+          '  }',
+        ].join('\n')
+      );
+    });
+
+    it('diffs against the template of the body, to show its matchers', () => {
+      const mismatches = [
+        {
+          type: 'BodyMismatch',
+          path: '$.name',
+          expected: '"Fred"',
+          actual: '"Mary"',
+          mismatch: "Expected 'Mary' to be equal to 'Fred'",
+        },
+      ] as unknown as Mismatch[];
+      const template = { id: integer(1), name: 'Fred' };
+
+      expect(bodyDiff(mismatches, { colour: false }, template)).to.eq(
+        [
+          '- Expected',
+          '+ Actual',
+          '',
+          '  {',
+          '    "id": 1,  // integer',
+          '-!  "name": "Fred"',
+          '+!  "name": "Mary"',
+          '  }',
+        ].join('\n')
+      );
+    });
+
+    it('does not render a diff without any body mismatches', () => {
+      const mismatches = [
+        {
+          type: 'HeaderMismatch',
+          key: 'Accept',
+          expected: 'application/json',
+          actual: 'text/plain',
+          mismatch: 'Mismatch with header Accept',
+        },
+      ] as unknown as Mismatch[];
+
+      expect(bodyDiff(mismatches, { colour: false })).to.eq(undefined);
+    });
+  });
+
+  describe('#isExpectedRequest', () => {
+    it('matches a request by the regex of its path', () => {
+      expect(
+        isExpectedRequest(
+          { method: 'GET', path: '/users/1', pattern: '^/users/\\d+$' },
+          { method: 'get', path: '/users/42' }
+        )
+      ).to.eq(true);
+    });
+
+    it('does not match a regex that JavaScript does not support', () => {
+      expect(
+        isExpectedRequest(
+          { method: 'GET', path: '/users/1', pattern: '(?i)^/USERS/\\d+$' },
+          { method: 'GET', path: '/users/42' }
+        )
+      ).to.eq(false);
+    });
+  });
+
+  describe('#displayRequest', () => {
+    it('truncates the body to the maximum length', () => {
+      const request = {
+        method: 'POST',
+        path: '/people',
+        body: { name: 'Fred' },
+      } as RequestMismatch;
+
+      expect(displayRequest(request, '', { maxBodyLength: 5 })).to.contain(
+        'Body: {"nam... (15 length)'
+      );
+    });
+  });
+
+  describe('#closestRequest', () => {
+    const expected = [
+      {
+        method: 'GET',
+        path: '/users/1',
+        query: { page: ['1'] },
+        headers: { Accept: ['application/json'] },
+      },
+      { method: 'POST', path: '/users' },
+      { method: 'GET', path: '/orders/1/items' },
+    ] as RequestMismatch[];
+
+    it('describes how the request differs from the closest expected one', () => {
+      expect(
+        closestRequest(
+          {
+            method: 'GET',
+            path: '/users/42',
+            headers: { accept: ['text/plain'] },
+          } as RequestMismatch,
+          expected
+        )
+      ).to.deep.eq({
+        request: expected[0],
+        differences: [
+          "path segment '42' should be '1'",
+          "query param 'page' missing",
+          "header 'Accept' is 'text/plain', expected 'application/json'",
+        ],
+      });
+    });
+
+    it('suggests a request with a different method', () => {
+      expect(
+        closestRequest(
+          { method: 'GET', path: '/users' } as RequestMismatch,
+          expected
+        )
+      ).to.deep.eq({
+        request: expected[1],
+        differences: ['method is GET, expected POST'],
+      });
+    });
+
+    it('does not suggest a request with a different path', () => {
+      expect(
+        closestRequest(
+          { method: 'GET', path: '/products/9/reviews' } as RequestMismatch,
+          expected
+        )
+      ).to.eq(undefined);
+    });
+
+    it('does not suggest a request with no segments of the path in common', () => {
+      expect(
+        closestRequest(
+          { method: 'GET', path: '/health' } as RequestMismatch,
+          expected
+        )
+      ).to.eq(undefined);
+    });
+  });
+
+  describe('#generateMockServerError', () => {
+    it('suggests the closest expected request for an unexpected one', () => {
+      const error = generateMockServerError(
+        [
+          {
+            type: 'request-not-found',
+            method: 'GET',
+            path: '/users/42',
+            request: { method: 'GET', path: '/users/42' },
+          },
+          {
+            type: 'missing-request',
+            method: 'GET',
+            path: '/users/1',
+            request: {
+              method: 'GET',
+              path: '/users/1',
+              query: { page: ['1'] },
+            },
+          },
+        ] as unknown as MatchingResult[],
+        '\t',
+        { colour: false }
+      );
+
+      expect(error).to.contain(
+        "Did you mean GET /users/1? path segment '42' should be '1', query param 'page' missing"
+      );
+    });
+
+    it('suggests the requests of the registered interactions', () => {
+      const error = generateMockServerError(
+        [
+          {
+            type: 'request-not-found',
+            method: 'GET',
+            path: '/users/42',
+            request: { method: 'GET', path: '/users/42' },
+          },
+        ] as unknown as MatchingResult[],
+        '\t',
+        { colour: false },
+        [{ method: 'GET', path: '/users/1', query: { page: ['1'] } }]
+      );
+
+      expect(error).to.contain(
+        "Did you mean GET /users/1? path segment '42' should be '1', query param 'page' missing"
+      );
+    });
+  });
+
+  describe('ContractMismatchError', () => {
+    const error = new ContractMismatchError(
+      'Test failed',
+      contractMismatches(results)
+    );
+
+    it('carries the structured mismatches', () => {
+      expect(error.message).to.eq('Test failed');
+      expect(error.mismatches).to.have.lengthOf(3);
+      expect(error).to.be.an.instanceOf(ContractMismatchError);
+    });
+
+    it('serialises the mismatches as JSON', () => {
+      const json = JSON.parse(JSON.stringify(error));
+
+      expect(json.name).to.eq('ContractMismatchError');
+      expect(json.message).to.eq('Test failed');
+      expect(json.mismatches[0].path).to.eq('$.items[0].id');
+    });
+
+    it('groups the mismatches by request when converted to a string', () => {
+      expect(error.toString()).to.eq(
+        [
+          'ContractMismatchError: 3 mismatches with the contract',
+          '',
+          '  1) The following request was incorrect: POST /orders',
+          "     $.items[0].id (BodyMismatch): Expected 'a' (String) to be the same type as 1 (Integer)",
+          '       Expected: 1',
+          '       Actual:   "a"',
+          "     Accept (HeaderMismatch): Mismatch with header 'Accept': Expected 'text/plain' to be equal to 'application/json'",
+          '       Expected: application/json',
+          '       Actual:   text/plain',
+          '',
+          '  2) The following request was expected but not received: GET /orders/1',
+        ].join('\n')
+      );
+    });
+  });
+});
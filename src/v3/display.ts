@@ -1,4 +1,4 @@
-import { join, toPairs, map, flatten } from 'ramda';
+import { join, toPairs, map, flatten, isNil, reject } from 'ramda';
 import {
   Mismatch,
   MatchingResult,
@@ -8,6 +8,61 @@ import {
   MatchingResultPlugin,
   PluginContentMismatch,
 } from '@pact-foundation/pact-core';
+import { ContractMismatch } from '../errors/contractMismatchError';
+import {
+  DiffOptions,
+  diffOptions,
+  renderDiff,
+  templateAt,
+  truncate,
+  withValuesAt,
+} from './diff';
+import { isMatcher, reify } from './matchers';
+
+/**
+ * A request added to a test. The mock server's results identify requests by
+ * their method and path, so they are matched to the expected requests by them
+ */
+export interface ExpectedRequest {
+  method: string;
+  /** The example path */
+  path: string;
+  /** The regular expression the path must match, for a `regex` matcher */
+  pattern?: string;
+  /** The example values of the query parameters */
+  query?: Record<string, string[]>;
+  /** The example values of the headers */
+  headers?: Record<string, string[]>;
+  /** The body template, so body diffs can show its matchers */
+  body?: unknown;
+}
+
+// The mock server uses Rust regular expressions, which support syntax that
+// JavaScript doesn't (e.g. inline flags). Paths can't be matched to those
+const matchesPattern = (pattern: string, path: string): boolean => {
+  try {
+    return new RegExp(pattern).test(path);
+  } catch (e) {
+    return false;
+  }
+};
+
+/**
+ * True if the request (from the mock server's results) is the expected one
+ */
+export const isExpectedRequest = (
+  expected: ExpectedRequest,
+  request: { method: string; path: string }
+): boolean =>
+  expected.method.toUpperCase() === request.method.toUpperCase() &&
+  (expected.path === request.path ||
+    (expected.pattern !== undefined &&
+      matchesPattern(expected.pattern, request.path)));
+
+const bodyTemplateFor = (
+  expected: ExpectedRequest[],
+  request: { method: string; path: string }
+): unknown => expected.find((e) => isExpectedRequest(e, request))?.body;
 
 // TODO: update Matching in the rust core to have a `type` property
 //       to avoid having to do this check!
@@ -58,7 +113,11 @@ function displayHeaders(
   );
 }
 
-export function displayRequest(request: RequestMismatch, indent = ''): string {
+export function displayRequest(
+  request: RequestMismatch,
+  indent = '',
+  options?: DiffOptions
+): string {
   const output: string[] = [''];
 
   output.push(
@@ -80,14 +139,186 @@ export function displayRequest(request: RequestMismatch, indent = ''): string {
 
   if (request.body) {
     const body = JSON.stringify(request.body);
+    const { maxBodyLength } = diffOptions(options);
     output.push(
-      `${indent}Body: ${body.substr(0, 20)}... (${body.length} length)`
+      `${indent}Body: ${truncate(body, maxBodyLength)} (${body.length} length)`
     );
   }
 
   return output.join('\n');
 }
 
+const pathSegments = (path: string): string[] =>
+  path.split('/').filter((segment) => segment !== '');
+
+// The number of segments to add, remove or change to turn one path into the
+// other
+const segmentDistance = (from: string[], to: string[]): number => {
+  let previous = to.map((_, j) => j + 1);
+  previous.unshift(0);
+  from.forEach((segment, i) => {
+    const current = [i + 1];
+    to.forEach((other, j) => {
+      current.push(
+        Math.min(
+          previous[j + 1] + 1,
+          current[j] + 1,
+          previous[j] + (segment === other ? 0 : 1)
+        )
+      );
+    });
+    previous = current;
+  });
+
+  return previous[to.length];
+};
+
+const displayValues = (values: string[] | string | undefined): string =>
+  `'${([] as string[]).concat(values ?? []).join(',')}'`;
+
+const pathDifferences = (actual: string, expected: string): string[] => {
+  const from = pathSegments(actual);
+  const to = pathSegments(expected);
+  if (from.length !== to.length) {
+    return [`path is '${actual}'`];
+  }
+
+  return from
+    .map((segment, i) =>
+      segment === to[i] ? '' : `path segment '${segment}' should be '${to[i]}'`
+    )
+    .filter(Boolean);
+};
+
+const queryDifferences = (
+  actual: Record<string, string[]> = {},
+  expected: Record<string, string[]> = {}
+): string[] => [
+  ...Object.keys(expected).map((key) => {
+    if (!(key in actual)) {
+      return `query param '${key}' missing`;
+    }
+    return displayValues(actual[key]) === displayValues(expected[key])
+      ? ''
+      : `query param '${key}' is ${displayValues(
+          actual[key]
+        )}, expected ${displayValues(expected[key])}`;
+  }),
+  ...Object.keys(actual)
+    .filter((key) => !(key in expected))
+    .map((key) => `unexpected query param '${key}'`),
+];
+
+// Headers are compared ignoring the case of the names, and any extra headers
+// in the actual request are allowed
+const headerDifferences = (
+  actual: Record<string, string[]> = {},
+  expected: Record<string, string[]> = {}
+): string[] => {
+  const actualValues = Object.keys(actual).reduce<Record<string, string[]>>(
+    (acc, key) => ({ ...acc, [key.toLowerCase()]: actual[key] }),
+    {}
+  );
+
+  return Object.keys(expected).map((key) => {
+    const value = actualValues[key.toLowerCase()];
+    if (value === undefined) {
+      return `header '${key}' missing`;
+    }
+    return displayValues(value) === displayValues(expected[key])
+      ? ''
+      : `header '${key}' is ${displayValues(value)}, expected ${displayValues(
+          expected[key]
+        )}`;
+  });
+};
+
+/**
+ * Finds the expected request that is closest to a request the mock server
+ * did not expect, and describes how they differ. A request is only suggested
+ * if the paths share at least one segment and no more than half of its path
+ * (or one segment) differs.
+ * @param request The request that was not expected
+ * @param candidates The requests that were expected
+ */
+export function closestRequest(
+  request: RequestMismatch,
+  candidates: RequestMismatch[]
+): { request: RequestMismatch; differences: string[] } | undefined {
+  const segments = pathSegments(request.path);
+  const closest = candidates
+    .map((candidate) => {
+      const expectedSegments = pathSegments(candidate.path);
+      const distance = segmentDistance(segments, expectedSegments);
+      const sameMethod =
+        request.method.toUpperCase() === candidate.method.toUpperCase();
+      const differences = [
+        sameMethod
+          ? ''
+          : `method is ${request.method}, expected ${candidate.method}`,
+        ...pathDifferences(request.path, candidate.path),
+        ...queryDifferences(request.query, candidate.query),
+        ...headerDifferences(request.headers, candidate.headers),
+      ].filter(Boolean);
+      const longest = Math.max(segments.length, expectedSegments.length);
+      const limit = Math.max(1, Math.floor(longest / 2));
+
+      return {
+        request: candidate,
+        differences,
+        eligible: distance === 0 || (distance <= limit && distance < longest),
+        score: distance * 3 + (sameMethod ? 0 : 2) + differences.length,
+      };
+    })
+    .filter((c) => c.eligible)
+    .sort((a, b) => a.score - b.score)[0];
+
+  return closest
+    ? { request: closest.request, differences: closest.differences }
+    : undefined;
+}
+
+// The requests that were expected but not received, and then the rest of the
+// requests of the registered interactions (e.g. one the request was meant to
+// repeat)
+const expectedCandidates = (
+  results: MatchingResult[],
+  expected: ExpectedRequest[]
+): RequestMismatch[] => {
+  const missing = results
+    .filter(
+      (r) => !isMismatchingResultPlugin(r) && r.type === 'missing-request'
+    )
+    .map((r) => (r as MatchingResultMissingRequest).request);
+  const registered = expected
+    .filter((e) => !missing.some((m) => isExpectedRequest(e, m)))
+    .map(
+      ({ method, path, query, headers }) =>
+        ({ method, path, query, headers } as RequestMismatch)
+    );
+
+  return [...missing, ...registered];
+};
+
+const displayClosestRequest = (
+  request: RequestMismatch,
+  results: MatchingResult[],
+  expected: ExpectedRequest[],
+  indent: string
+): string => {
+  const closest = closestRequest(
+    request,
+    expectedCandidates(results, expected)
+  );
+  if (!closest) {
+    return '';
+  }
+
+  return `\n${indent}Did you mean ${closest.request.method} ${
+    closest.request.path
+  }? ${closest.differences.join(', ')}`;
+};
+
 export function filterMissingFeatureFlag(
   mismatches: MatchingResult[]
 ): MatchingResult[] {
@@ -99,6 +330,85 @@ export function filterMissingFeatureFlag(
   return mismatches;
 }
 
+const indentLines = (text: string, indent: string): string =>
+  text
+    .split('\n')
+    .map((line) => `${indent}${line}`)
+    .join('\n');
+
+const parseBody = (value: unknown): unknown => {
+  const text = Buffer.isBuffer(value) ? value.toString() : value;
+  if (typeof text !== 'string') {
+    return text;
+  }
+  try {
+    return JSON.parse(text);
+  } catch (e) {
+    return undefined;
+  }
+};
+
+// Buffers (e.g. plugin content) are converted to strings so the mismatch can
+// be serialised
+const contentValue = (value: unknown): unknown =>
+  Buffer.isBuffer(value) ? value.toString() : value;
+
+interface BodyMismatchFields {
+  type?: string;
+  path?: string;
+  expected?: unknown;
+  actual?: unknown;
+}
+
+const isBodyMismatch = (m: Mismatch): boolean => {
+  const { type } = m as BodyMismatchFields;
+  return type === 'BodyMismatch' || type === 'BodyTypeMismatch';
+};
+
+// Values that aren't JSON (e.g. of a text body) are shown as they are
+const bodyValue = (value: unknown): unknown => {
+  const parsed = parseBody(value);
+  return parsed === undefined ? contentValue(value) : parsed;
+};
+
+/**
+ * Renders a diff of the whole expected body of a request against the actual
+ * body, with the paths of its body mismatches highlighted (see
+ * {@link renderDiff}). The core only reports the values at the paths that
+ * failed to match, so the actual body is the expected one with those values
+ * in place, and the values that matched are shown as their examples. The
+ * expected body is the template where it is given, to show its matchers, or
+ * is made up of the expected values of the mismatches.
+ * @param mismatches Mismatches of the request from the mock server
+ * @param options Format and limits of the diff
+ * @param template Template of the expected body
+ */
+export function bodyDiff(
+  mismatches: Mismatch[],
+  options?: DiffOptions,
+  template?: unknown
+): string | undefined {
+  const body = (mismatches.filter(isBodyMismatch) as BodyMismatchFields[]).map(
+    (m) => ({ ...m, path: m.path?.startsWith('$') ? m.path : '$' })
+  );
+  if (body.length === 0) {
+    return undefined;
+  }
+  const valuesOf = (field: 'expected' | 'actual') =>
+    body.map((m) => ({ path: m.path, value: bodyValue(m[field]) }));
+  const expected =
+    template !== undefined
+      ? template
+      : withValuesAt(undefined, valuesOf('expected'));
+  const example = template !== undefined ? reify(template) : expected;
+
+  return renderDiff(expected, withValuesAt(example, valuesOf('actual')), {
+    ...options,
+    // A mismatch of the whole body is shown by the diff itself
+    highlight: body.map((m) => m.path).filter((path) => path !== '$'),
+  });
+}
+
 export function printMismatch(m: Mismatch): string {
   if (isPluginContentMismatch(m)) {
     const s = [
@@ -128,9 +438,19 @@ export function printMismatches(mismatches: Mismatch[]): string {
   return errors.join('\n');
 }
 
+/**
+ * Describes the mismatches from the mock server
+ * @param mismatches Results from the mock server
+ * @param indent Indentation of each mismatch
+ * @param options Format and limits of body diffs
+ * @param expected The requests added to the test, to show the matchers of
+ * their bodies in diffs
+ */
 export function generateMockServerError(
   mismatches: MatchingResult[],
-  indent: string
+  indent: string,
+  options?: DiffOptions,
+  expected: ExpectedRequest[] = []
 ): string {
   return [
     'Mock server failed with the following mismatches:',
@@ -139,6 +459,14 @@ export function generateMockServerError(
         return printMismatches(mismatch.mismatches);
       }
       if (mismatch.type === 'request-mismatch') {
+        const diff = bodyDiff(
+          mismatch.mismatches || [],
+          options,
+          bodyTemplateFor(expected, mismatch)
+        );
+        const diffLines = diff
+          ? `\n\n${indentLines(diff, `${indent}${indent}${indent}    `)}`
+          : '';
         return `\n${indent}${i}) The following request was incorrect: \n
             ${indent}${mismatch.method} ${mismatch.path}
             ${mismatch.mismatches
@@ -146,21 +474,157 @@ export function generateMockServerError(
                 (d, j) =>
                   `\n${indent}${indent}${indent} 1.${j} ${printMismatch(d)}`
               )
-              .join('')}`;
+              .join('')}${diffLines}`;
       }
       if (mismatch.type === 'request-not-found') {
+        const { request } = mismatch as MatchingResultRequestNotFound;
         return `\n${indent}${i}) The following request was not expected: ${displayRequest(
-          (mismatch as MatchingResultRequestNotFound).request,
+          request,
+          `${indent}    `,
+          options
+        )}${displayClosestRequest(
+          request,
+          mismatches,
+          expected,
           `${indent}    `
         )}`;
       }
       if (mismatch.type === 'missing-request') {
         return `\n${indent}${i}) The following request was expected but not received: ${displayRequest(
           (mismatch as MatchingResultMissingRequest).request,
-          `${indent}    `
+          `${indent}    `,
+          options
         )}`;
       }
       return `Unknown mismatch: ${mismatch}`;
     }),
   ].join('\n');
 }
+
+const withoutEmptyFields = (m: ContractMismatch): ContractMismatch =>
+  reject(isNil, m) as ContractMismatch;
+
+// The core doesn't report which matching rule failed, so it is only given for
+// a path of the body template that has a matcher
+const matcherTypeAt = (
+  template: unknown,
+  path: string | undefined
+): string | undefined => {
+  const node =
+    template !== undefined && path?.startsWith('$')
+      ? templateAt(template, path)
+      : undefined;
+
+  return isMatcher(node) ? node['pact:matcher:type'] : undefined;
+};
+
+// Mismatches from the core and from plugins share most of their fields, so
+// they are read loosely here rather than switching on the type
+const toContractMismatch = (
+  m: Mismatch,
+  request: { method: string; path: string },
+  template?: unknown,
+  diff?: string
+): ContractMismatch => {
+  const fields = m as {
+    type?: string;
+    path?: string;
+    key?: string;
+    parameter?: string;
+    expected?: unknown;
+    actual?: unknown;
+    mismatch?: string;
+    diff?: string;
+  };
+  const mismatch =
+    fields.mismatch || `Expected ${fields.expected}, got: ${fields.actual}`;
+
+  return withoutEmptyFields({
+    kind: 'request-mismatch',
+    request,
+    type: fields.type,
+    path: fields.path || fields.key || fields.parameter,
+    expected: contentValue(fields.expected),
+    actual: contentValue(fields.actual),
+    matcherType: matcherTypeAt(template, fields.path),
+    mismatch,
+    diff: fields.diff ?? diff,
+  });
+};
+
+/**
+ * Converts the results from the mock server into a list of mismatches, one
+ * per problem found with each request
+ * @param results Results from the mock server
+ * @param options Format and limits of body diffs
+ * @param expected The requests added to the test, to show the matchers of
+ * their bodies in diffs
+ */
+export function contractMismatches(
+  results: MatchingResult[],
+  options?: DiffOptions,
+  expected: ExpectedRequest[] = []
+): ContractMismatch[] {
+  return flatten(
+    results.map((result): ContractMismatch[] => {
+      if (isMismatchingResultPlugin(result)) {
+        return result.mismatches.map((m) =>
+          withoutEmptyFields({
+            kind: 'plugin-mismatch',
+            path: m.path,
+            expected: contentValue(m.expected),
+            actual: contentValue(m.actual),
+            mismatch: m.mismatch || result.error,
+            diff: m.diff,
+          })
+        );
+      }
+      switch (result.type) {
+        case 'request-mismatch': {
+          const request = { method: result.method, path: result.path };
+          const template = bodyTemplateFor(expected, request);
+          const mismatches = result.mismatches || [];
+          // The diff is of the whole body, so is only given once
+          const diffed = mismatches.find(isBodyMismatch);
+          const diff = bodyDiff(
+            mismatches,
+            { ...options, colour: false },
+            template
+          );
+          return mismatches.map((m) =>
+            toContractMismatch(
+              m,
+              request,
+              template,
+              m === diffed ? diff : undefined
+            )
+          );
+        }
+        case 'request-not-found': {
+          const { request } = result as MatchingResultRequestNotFound;
+          return [
+            {
+              kind: 'request-not-found',
+              request: { method: request.method, path: request.path },
+              actual: request,
+              mismatch: 'The request was not expected',
+            },
+          ];
+        }
+        case 'missing-request': {
+          const { request } = result as MatchingResultMissingRequest;
+          return [
+            {
+              kind: 'missing-request',
+              request: { method: request.method, path: request.path },
+              expected: request,
+              mismatch: 'The request was expected but not received',
+            },
+          ];
+        }
+        default:
+          return [];
+      }
+    })
+  );
+}
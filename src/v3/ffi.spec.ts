@@ -0,0 +1,234 @@
+import { ConsumerInteraction } from '@pact-foundation/pact-core';
+import chai from 'chai';
+import sinon from 'sinon';
+import sinonChai from 'sinon-chai';
+import {
+  contentTypeFromHeaders,
+  setRequestBody,
+  setRequestHeaders,
+  setResponseBody,
+  setResponseHeaders,
+  validateFormBody,
+  validateJsonContentType,
+  validateTextBody,
+  validateXmlContentType,
+} from './ffi';
+import { includes, integer, like, regex } from './matchers';
+import { XmlBuilder } from './xml/xmlBuilder';
+
+chai.use(sinonChai);
+
+const { expect } = chai;
+
+describe('V3 Pact FFI', () => {
+  describe('#setRequestHeaders', () => {
+    it('calls the header ffi function for each value of a multi-valued header', () => {
+      const headerMock = sinon.stub();
+      const interaction = {
+        withRequestHeader: headerMock,
+      } as unknown as ConsumerInteraction;
+
+      setRequestHeaders(interaction, {
+        Accept: ['application/json', 'text/plain'],
+        'X-Single': 'foo',
+      });
+
+      expect(headerMock).to.have.been.calledThrice;
+      expect(headerMock).to.have.been.calledWith(
+        'Accept',
+        0,
+        'application/json'
+      );
+      expect(headerMock).to.have.been.calledWith('Accept', 1, 'text/plain');
+      expect(headerMock).to.have.been.calledWith('X-Single', 0, 'foo');
+    });
+  });
+
+  describe('#setResponseHeaders', () => {
+    it('calls the header ffi function for each value of a multi-valued header', () => {
+      const headerMock = sinon.stub();
+      const interaction = {
+        withResponseHeader: headerMock,
+      } as unknown as ConsumerInteraction;
+      const cookie = regex('^session=\\w+$', 'session=abc');
+
+      setResponseHeaders(interaction, {
+        'Set-Cookie': [cookie, 'theme=dark'],
+      });
+
+      expect(headerMock).to.have.been.calledTwice;
+      expect(headerMock).to.have.been.calledWith(
+        'Set-Cookie',
+        0,
+        JSON.stringify(cookie)
+      );
+      expect(headerMock).to.have.been.calledWith('Set-Cookie', 1, 'theme=dark');
+    });
+
+    it('does nothing when no headers are given', () => {
+      const headerMock = sinon.stub();
+      const interaction = {
+        withResponseHeader: headerMock,
+      } as unknown as ConsumerInteraction;
+
+      setResponseHeaders(interaction, undefined);
+
+      expect(headerMock).not.to.have.been.called;
+    });
+  });
+
+  describe('#validateFormBody', () => {
+    it('accepts strings, string matchers and repeated fields', () => {
+      expect(() =>
+        validateFormBody({
+          name: 'fred',
+          id: regex('\\d+', '1234'),
+          tag: ['a', regex('[a-z]', 'b')],
+        })
+      ).not.to.throw();
+    });
+
+    it('rejects fields that are not strings', () => {
+      expect(() => validateFormBody({ id: 1234 })).to.throw(
+        "Form field 'id' must only contain strings or string matchers"
+      );
+    });
+
+    it('rejects a body that is not an object', () => {
+      expect(() => validateFormBody('name=fred')).to.throw();
+      expect(() => validateFormBody(['name', 'fred'])).to.throw();
+    });
+  });
+
+  describe('#validateTextBody', () => {
+    it('accepts strings and matchers that apply to the whole body', () => {
+      expect(() => validateTextBody('some text')).not.to.throw();
+      expect(() => validateTextBody(regex('^\\w+$', 'text'))).not.to.throw();
+      expect(() => validateTextBody(includes('text'))).not.to.throw();
+      expect(() => validateTextBody(like('text'))).not.to.throw();
+    });
+
+    it('rejects matchers that do not match a string', () => {
+      expect(() => validateTextBody(integer(1))).to.throw(
+        'A text body must be a string, or a matcher with a string example'
+      );
+      expect(() => validateTextBody({ some: 'json' })).to.throw();
+    });
+  });
+
+  describe('#contentTypeFromHeaders', () => {
+    it('uses the example value of a matcher on the Content-Type header', () => {
+      expect(
+        contentTypeFromHeaders(
+          {
+            'Content-Type': regex(
+              '^application/(.+\\+)?json',
+              'application/vnd.api+json'
+            ),
+          },
+          'application/json'
+        )
+      ).to.eq('application/vnd.api+json');
+    });
+  });
+
+  describe('#setRequestBody', () => {
+    it('sends a matcher template for a +json media type', () => {
+      const bodyMock = sinon.stub();
+      const interaction = {
+        withRequestBody: bodyMock,
+      } as unknown as ConsumerInteraction;
+      const body = { data: { id: like('1') } };
+
+      setRequestBody(interaction, {
+        method: 'POST',
+        path: '/articles',
+        headers: {
+          'Content-Type': 'application/vnd.api+json; charset=utf-8',
+        },
+        body,
+      });
+
+      expect(bodyMock).to.have.been.calledWith(
+        JSON.stringify(body),
+        'application/vnd.api+json; charset=utf-8'
+      );
+    });
+  });
+
+  describe('#setResponseBody', () => {
+    const bodyMock = sinon.stub();
+    const interaction = {
+      withResponseBody: bodyMock,
+    } as unknown as ConsumerInteraction;
+
+    afterEach(() => bodyMock.reset());
+
+    it('sends a text/xml body built with the XmlBuilder', () => {
+      const body = new XmlBuilder('1.0', 'UTF-8', 'note').build((el) => {
+        el.setAttributes({ id: integer(1) });
+      });
+
+      setResponseBody(interaction, {
+        status: 200,
+        headers: { 'Content-Type': 'text/xml' },
+        body,
+      });
+
+      expect(bodyMock).to.have.been.calledWith(body, 'text/xml');
+    });
+
+    it('sends a text body with a type matcher', () => {
+      setResponseBody(interaction, {
+        status: 200,
+        headers: { 'Content-Type': 'text/html' },
+        body: like('<p>Hello</p>'),
+      });
+
+      expect(bodyMock).to.have.been.calledWith(
+        JSON.stringify(like('<p>Hello</p>')),
+        'text/html'
+      );
+    });
+
+    it('rejects a whole text body matcher that does not match a string', () => {
+      expect(() =>
+        setResponseBody(interaction, {
+          status: 200,
+          headers: { 'Content-Type': 'text/plain' },
+          body: integer(1),
+        })
+      ).to.throw('A text body must be a string');
+    });
+  });
+
+  describe('#validateJsonContentType', () => {
+    it('accepts JSON media types, including those with the +json suffix', () => {
+      expect(() => validateJsonContentType('application/json')).not.to.throw();
+      expect(() =>
+        validateJsonContentType('application/hal+json; charset=utf-8')
+      ).not.to.throw();
+    });
+
+    it('rejects other media types', () => {
+      expect(() => validateJsonContentType('text/plain')).to.throw(
+        "'text/plain' is not a JSON content type"
+      );
+    });
+  });
+
+  describe('#validateXmlContentType', () => {
+    it('accepts XML media types, including those with the +xml suffix', () => {
+      expect(() => validateXmlContentType('text/xml')).not.to.throw();
+      expect(() =>
+        validateXmlContentType('application/atom+xml')
+      ).not.to.throw();
+    });
+
+    it('rejects other media types', () => {
+      expect(() => validateXmlContentType('application/json')).to.throw(
+        "'application/json' is not an XML content type"
+      );
+    });
+  });
+});
@@ -1,10 +1,59 @@
 import { forEachObjIndexed } from 'ramda';
 import { ConsumerInteraction } from '@pact-foundation/pact-core';
-import { Matcher, TemplateHeaders, V3Request, V3Response } from './types';
+import {
+  Matcher,
+  TemplateFormBody,
+  TemplateHeaders,
+  V3Request,
+  V3Response,
+} from './types';
 import * as MatchersV3 from './matchers';
+import ConfigurationError from '../errors/configurationError';
+import {
+  isJsonContentType,
+  isXmlContentType,
+  mediaType,
+} from '../common/contentType';
+
+export const CONTENT_TYPE_FORM_URLENCODED = 'application/x-www-form-urlencoded';
 
 type TemplateHeaderArrayValue = string[] | Matcher<string>[];
 
+// Each value of a multi-valued header (e.g. Set-Cookie) is registered
+// against its own index, so that every value can carry its own matcher
+const forEachHeaderValue = (
+  headers: TemplateHeaders | undefined,
+  fn: (name: string, index: number, value: string) => void
+): void => {
+  forEachObjIndexed((v, k) => {
+    if (Array.isArray(v)) {
+      (v as TemplateHeaderArrayValue).forEach((header, index) => {
+        fn(`${k}`, index, MatchersV3.matcherValueOrString(header));
+      });
+    } else {
+      fn(`${k}`, 0, MatchersV3.matcherValueOrString(v));
+    }
+  }, headers || {});
+};
+
+export const setRequestHeaders = (
+  interaction: ConsumerInteraction,
+  headers?: TemplateHeaders
+): void => {
+  forEachHeaderValue(headers, (name, index, value) => {
+    interaction.withRequestHeader(name, index, value);
+  });
+};
+
+export const setResponseHeaders = (
+  interaction: ConsumerInteraction,
+  headers?: TemplateHeaders
+): void => {
+  forEachHeaderValue(headers, (name, index, value) => {
+    interaction.withResponseHeader(name, index, value);
+  });
+};
+
 export const setRequestDetails = (
   interaction: ConsumerInteraction,
   req: V3Request
@@ -13,19 +62,7 @@ export const setRequestDetails = (
     req.method,
     MatchersV3.matcherValueOrString(req.path)
   );
-  forEachObjIndexed((v, k) => {
-    if (Array.isArray(v)) {
-      (v as TemplateHeaderArrayValue).forEach((header, index) => {
-        interaction.withRequestHeader(
-          k,
-          index,
-          MatchersV3.matcherValueOrString(header)
-        );
-      });
-    } else {
-      interaction.withRequestHeader(k, 0, MatchersV3.matcherValueOrString(v));
-    }
-  }, req.headers);
+  setRequestHeaders(interaction, req.headers);
 
   forEachObjIndexed((v, k) => {
     if (Array.isArray(v)) {
@@ -44,32 +81,143 @@ export const setResponseDetails = (
 ): void => {
   interaction.withStatus(res.status);
 
-  forEachObjIndexed((v, k) => {
-    if (Array.isArray(v)) {
-      (v as TemplateHeaderArrayValue).forEach((header, index) => {
-        interaction.withResponseHeader(
-          k,
-          index,
-          MatchersV3.matcherValueOrString(header)
-        );
-      });
-    } else {
-      interaction.withResponseHeader(k, 0, MatchersV3.matcherValueOrString(v));
-    }
-  }, res.headers);
+  setResponseHeaders(interaction, res.headers);
 };
 
 // TODO: this might need to consider an array of values
+// A matcher on the header (e.g. a regex for any JSON media type) uses its
+// example value as the content type
 export const contentTypeFromHeaders = (
   headers: TemplateHeaders | undefined,
   defaultContentType: string
 ): string => {
-  let contentType: string | Matcher<string> = defaultContentType;
+  let contentType = defaultContentType;
   forEachObjIndexed((v, k) => {
     if (`${k}`.toLowerCase() === 'content-type') {
-      contentType = MatchersV3.matcherValueOrString(v);
+      contentType = MatchersV3.isMatcher(v)
+        ? `${v.value}`
+        : MatchersV3.matcherValueOrString(v);
     }
   }, headers || {});
 
   return contentType;
 };
+
+const isFormFieldValue = (v: unknown): boolean =>
+  typeof v === 'string' ||
+  (MatchersV3.isMatcher(v) && typeof v.value === 'string');
+
+/**
+ * Checks that a form body only contains string values (or matchers of
+ * strings). The core converts the template into the encoded form body,
+ * registering a matching rule for each field that uses a matcher.
+ */
+export const validateFormBody = (body: unknown): void => {
+  if (typeof body !== 'object' || body === null || Array.isArray(body)) {
+    throw new ConfigurationError(
+      'A form body must be an object of field names to values'
+    );
+  }
+
+  forEachObjIndexed((v, k) => {
+    const values = Array.isArray(v) ? v : [v];
+    if (!values.every(isFormFieldValue)) {
+      throw new ConfigurationError(
+        `Form field '${k}' must only contain strings or string matchers`
+      );
+    }
+  }, body as TemplateFormBody);
+};
+
+/**
+ * Checks that a text body is either a string, or a matcher with a string
+ * example that can be applied to the body as a whole (e.g. `regex` or
+ * `includes`).
+ */
+export const validateTextBody = (body: unknown): void => {
+  if (typeof body === 'string') {
+    return;
+  }
+
+  if (!MatchersV3.isMatcher(body) || typeof body.value !== 'string') {
+    throw new ConfigurationError(
+      'A text body must be a string, or a matcher with a string example (e.g. regex or includes)'
+    );
+  }
+};
+
+/**
+ * Checks that the content type of a JSON body is JSON based, i.e.
+ * application/json or a media type with the +json suffix (e.g.
+ * application/hal+json), so that the core applies the matchers in the body
+ */
+export const validateJsonContentType = (contentType: string): void => {
+  if (!isJsonContentType(contentType)) {
+    throw new ConfigurationError(
+      `'${contentType}' is not a JSON content type. Use application/json, or a media type with the +json suffix (e.g. application/hal+json)`
+    );
+  }
+};
+
+/**
+ * Checks that the content type of an XML body is XML based, i.e.
+ * application/xml, text/xml or a media type with the +xml suffix (e.g.
+ * application/atom+xml)
+ */
+export const validateXmlContentType = (contentType: string): void => {
+  if (!isXmlContentType(contentType)) {
+    throw new ConfigurationError(
+      `'${contentType}' is not an XML content type. Use application/xml, or a media type with the +xml suffix (e.g. application/atom+xml)`
+    );
+  }
+};
+
+const bodyForContentType = (body: unknown, contentType: string): string => {
+  const type = mediaType(contentType);
+
+  if (type === CONTENT_TYPE_FORM_URLENCODED) {
+    validateFormBody(body);
+  } else if (
+    type.startsWith('text/') &&
+    !isJsonContentType(type) &&
+    !isXmlContentType(type) &&
+    MatchersV3.isMatcher(body)
+  ) {
+    // The core applies a matcher to a whole text body, so it must match a
+    // string. Other text bodies are sent as they are.
+    validateTextBody(body);
+  }
+  MatchersV3.validateTemplate(body);
+
+  return MatchersV3.matcherValueOrString(body);
+};
+
+export const setRequestBody = (
+  interaction: ConsumerInteraction,
+  req: V3Request
+): void => {
+  if (req.body) {
+    const contentType =
+      req.contentType ||
+      contentTypeFromHeaders(req.headers, 'application/json');
+    interaction.withRequestBody(
+      bodyForContentType(req.body, contentType),
+      contentType
+    );
+  }
+};
+
+export const setResponseBody = (
+  interaction: ConsumerInteraction,
+  res: V3Response
+): void => {
+  if (res.body) {
+    const contentType =
+      res.contentType ||
+      contentTypeFromHeaders(res.headers, 'application/json');
+    interaction.withResponseBody(
+      bodyForContentType(res.body, contentType),
+      contentType
+    );
+  }
+};
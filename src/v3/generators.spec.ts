@@ -0,0 +1,81 @@
+import * as chai from 'chai';
+import * as Generators from './generators';
+
+const { expect } = chai;
+
+describe('V3 Generators', () => {
+  describe('#randomInt', () => {
+    it('returns a RandomInt generator with the range', () => {
+      expect(Generators.randomInt(1, 100)).to.deep.equal({
+        'pact:generator:type': 'RandomInt',
+        min: 1,
+        max: 100,
+      });
+    });
+
+    it('throws an exception if the minimum is greater than the maximum', () => {
+      expect(() => Generators.randomInt(10, 1)).to.throw(
+        'randomInt has a minimum of 10, which is greater than the maximum of 1'
+      );
+    });
+  });
+
+  describe('random value generators', () => {
+    it('throws an exception if the number of digits or size is not positive', () => {
+      expect(() => Generators.randomDecimal(0)).to.throw(
+        'randomDecimal must have a number of digits of at least 1, but was given 0'
+      );
+      expect(() => Generators.randomHex(-1)).to.throw(/^randomHex must/);
+      expect(() => Generators.randomString(1.5)).to.throw(
+        'randomString must have a size of at least 1, but was given 1.5'
+      );
+    });
+
+    it('accepts a positive number of digits or size', () => {
+      expect(Generators.randomHex(1)).to.deep.equal({
+        'pact:generator:type': 'RandomHexadecimal',
+        digits: 1,
+      });
+    });
+  });
+
+  describe('#datetime', () => {
+    it('only includes the attributes that are given', () => {
+      expect(Generators.datetime()).to.deep.equal({
+        'pact:generator:type': 'DateTime',
+      });
+      expect(
+        Generators.datetime("yyyy-MM-dd'T'HH:mm:ss", '+ 1 day')
+      ).to.deep.equal({
+        'pact:generator:type': 'DateTime',
+        format: "yyyy-MM-dd'T'HH:mm:ss",
+        expression: '+ 1 day',
+      });
+    });
+  });
+
+  describe('#mockServerURL', () => {
+    it('returns a MockServerURL generator with the example and regex', () => {
+      expect(
+        Generators.mockServerURL(
+          'http://localhost:8080/orders/1',
+          '.*(/orders/\\d+)$'
+        )
+      ).to.deep.equal({
+        'pact:generator:type': 'MockServerURL',
+        example: 'http://localhost:8080/orders/1',
+        regex: '.*(/orders/\\d+)$',
+      });
+    });
+  });
+
+  describe('#providerState', () => {
+    it('returns a ProviderState generator with the expression', () => {
+      expect(Generators.providerState('${id}')).to.deep.equal({
+        'pact:generator:type': 'ProviderState',
+        // eslint-disable-next-line no-template-curly-in-string
+        expression: '${id}',
+      });
+    });
+  });
+});
@@ -0,0 +1,164 @@
+import { isNil, pickBy } from 'ramda';
+import { ValueGenerator } from './types';
+import MatcherError from '../errors/matcherError';
+
+const validateCount = (name: string, argument: string, count: number) => {
+  if (!Number.isInteger(count) || count < 1) {
+    throw new MatcherError(
+      `${name} must have a ${argument} of at least 1, but was given ${count}`
+    );
+  }
+};
+
+/**
+ * Generates a random integer between the minimum and maximum values (inclusive)
+ * @param min Minimum value. Defaults to 0
+ * @param max Maximum value. Defaults to 2147483647
+ */
+export function randomInt(min = 0, max = 2147483647): ValueGenerator {
+  if (min > max) {
+    throw new MatcherError(
+      `randomInt has a minimum of ${min}, which is greater than the maximum of ${max}`
+    );
+  }
+
+  return {
+    'pact:generator:type': 'RandomInt',
+    min,
+    max,
+  };
+}
+
+/**
+ * Generates a random decimal number
+ * @param digits Number of digits to generate. Defaults to 10
+ */
+export function randomDecimal(digits = 10): ValueGenerator {
+  validateCount('randomDecimal', 'number of digits', digits);
+
+  return {
+    'pact:generator:type': 'RandomDecimal',
+    digits,
+  };
+}
+
+/**
+ * Generates a random hexadecimal string
+ * @param digits Number of digits to generate. Defaults to 10
+ */
+export function randomHex(digits = 10): ValueGenerator {
+  validateCount('randomHex', 'number of digits', digits);
+
+  return {
+    'pact:generator:type': 'RandomHexadecimal',
+    digits,
+  };
+}
+
+/**
+ * Generates a random alphanumeric string
+ * @param size Length of the string to generate. Defaults to 10
+ */
+export function randomString(size = 10): ValueGenerator {
+  validateCount('randomString', 'size', size);
+
+  return {
+    'pact:generator:type': 'RandomString',
+    size,
+  };
+}
+
+/**
+ * Generates a random boolean value
+ */
+export function randomBoolean(): ValueGenerator {
+  return {
+    'pact:generator:type': 'RandomBoolean',
+  };
+}
+
+/**
+ * Generates a string from a regular expression
+ * @param pattern Regular expression to generate the value from
+ */
+export function regex(pattern: RegExp | string): ValueGenerator {
+  return {
+    'pact:generator:type': 'Regex',
+    regex: pattern instanceof RegExp ? pattern.source : pattern,
+  };
+}
+
+/**
+ * Generates a random UUID
+ */
+export function uuid(): ValueGenerator {
+  return {
+    'pact:generator:type': 'Uuid',
+  };
+}
+
+const dateTimeGenerator = (
+  type: string,
+  format?: string,
+  expression?: string
+): ValueGenerator =>
+  pickBy((v) => !isNil(v), {
+    'pact:generator:type': type,
+    format,
+    expression,
+  }) as ValueGenerator;
+
+/**
+ * Generates a date from the current system date
+ * @param format Date format string. See [Java SimpleDateFormat](https://docs.oracle.com/javase/8/docs/api/java/text/SimpleDateFormat.html). Defaults to the format of the matcher, or ISO format
+ * @param expression Offset from the current date, e.g. '+ 1 day' or 'next monday'
+ */
+export function date(format?: string, expression?: string): ValueGenerator {
+  return dateTimeGenerator('Date', format, expression);
+}
+
+/**
+ * Generates a time from the current system time
+ * @param format Time format string. See [Java SimpleDateFormat](https://docs.oracle.com/javase/8/docs/api/java/text/SimpleDateFormat.html). Defaults to the format of the matcher, or ISO format
+ * @param expression Offset from the current time, e.g. '+ 2 hours' or 'midnight'
+ */
+export function time(format?: string, expression?: string): ValueGenerator {
+  return dateTimeGenerator('Time', format, expression);
+}
+
+/**
+ * Generates a date and time from the current system date and time
+ * @param format Datetime format string. See [Java SimpleDateFormat](https://docs.oracle.com/javase/8/docs/api/java/text/SimpleDateFormat.html). Defaults to the format of the matcher, or ISO format
+ * @param expression Offset from the current date and time, e.g. 'tomorrow' or '+ 1 week'
+ */
+export function datetime(format?: string, expression?: string): ValueGenerator {
+  return dateTimeGenerator('DateTime', format, expression);
+}
+
+/**
+ * Replaces the base URL of a URL with the base URL of the running mock server
+ * @param example Example URL, e.g. 'http://localhost:8080/orders/1'
+ * @param pattern Regular expression with a group that matches the path of the URL, e.g. '.*(/orders/\d+)$'
+ */
+export function mockServerURL(
+  example: string,
+  pattern: string
+): ValueGenerator {
+  return {
+    'pact:generator:type': 'MockServerURL',
+    example,
+    regex: pattern,
+  };
+}
+
+/**
+ * Generates a value from an expression that is resolved against the values
+ * returned from the provider state callbacks during verification
+ * @param expression Expression to lookup in the provider state context, e.g. '${id}'
+ */
+export function providerState(expression: string): ValueGenerator {
+  return {
+    'pact:generator:type': 'ProviderState',
+    expression,
+  };
+}
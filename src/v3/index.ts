@@ -1,5 +1,9 @@
 export * from './pact';
 export * from './types';
+export * from './csv';
+export * from './jsonSchema';
+export * from './openapi';
+export { DiffOptions, RenderDiffOptions, renderDiff } from './diff';
 
 /**
  * Exposes {@link MatchersV3}
@@ -8,6 +12,13 @@ export * from './types';
  */
 export * as MatchersV3 from './matchers';
 
+/**
+ * Exposes {@link Generators}
+ * @memberof Pact
+ * @static
+ */
+export * as Generators from './generators';
+
 /**
  * Exposes {@link xml}
  * @memberof Pact
@@ -0,0 +1,197 @@
+import * as chai from 'chai';
+import { fromJsonSchema, JsonSchema } from './jsonSchema';
+import * as MatchersV3 from './matchers';
+
+const { expect } = chai;
+
+describe('JSON Schema templates', () => {
+  describe('#fromJsonSchema', () => {
+    it('maps the JSON types to type matchers, using the examples given', () => {
+      const schema: JsonSchema = {
+        type: 'object',
+        required: ['id', 'name', 'price', 'active', 'deleted'],
+        properties: {
+          id: { type: 'integer', examples: [10] },
+          name: { type: 'string', default: 'Fred' },
+          price: { type: 'number', example: 1.5 },
+          active: { type: 'boolean' },
+          deleted: { type: 'null' },
+        },
+      };
+
+      expect(fromJsonSchema(schema)).to.deep.equal({
+        id: MatchersV3.integer(10),
+        name: MatchersV3.string('Fred'),
+        price: MatchersV3.number(1.5),
+        active: MatchersV3.boolean(true),
+        deleted: MatchersV3.nullValue(),
+      });
+    });
+
+    it('only includes the required properties', () => {
+      const schema: JsonSchema = {
+        type: 'object',
+        required: ['id'],
+        properties: {
+          id: { type: 'integer', examples: [10] },
+          nickname: { type: 'string' },
+        },
+      };
+
+      expect(fromJsonSchema(schema)).to.have.all.keys('id');
+    });
+
+    it('maps formats, patterns and enums to the matching matchers', () => {
+      const schema: JsonSchema = {
+        type: 'object',
+        required: ['created', 'day', 'id', 'code', 'status'],
+        properties: {
+          created: { type: 'string', format: 'date-time' },
+          day: { type: 'string', format: 'date', example: '2020-02-01' },
+          id: {
+            type: 'string',
+            format: 'uuid',
+            example: 'adc214d3-1c9f-460d-b6c8-8f2bc8911860',
+          },
+          code: { type: 'string', pattern: '^[A-Z]{3}$', example: 'ABC' },
+          status: { type: 'string', enum: ['open', 'closed'] },
+        },
+      };
+
+      expect(fromJsonSchema(schema)).to.deep.equal({
+        created: MatchersV3.datetime(
+          "yyyy-MM-dd'T'HH:mm:ss.SSSXXX",
+          '2000-01-01T00:00:00.000Z'
+        ),
+        day: MatchersV3.date('yyyy-MM-dd', '2020-02-01'),
+        id: MatchersV3.uuid('adc214d3-1c9f-460d-b6c8-8f2bc8911860'),
+        code: MatchersV3.regex('^[A-Z]{3}$', 'ABC'),
+        status: MatchersV3.regex('^(?:open|closed)$', 'open'),
+      });
+    });
+
+    it('uses a date-time format that matches the example', () => {
+      expect(
+        fromJsonSchema({
+          type: 'string',
+          format: 'date-time',
+          example: '2020-02-01T10:00:00Z',
+        })
+      ).to.deep.equal(
+        MatchersV3.datetime("yyyy-MM-dd'T'HH:mm:ssXXX", '2020-02-01T10:00:00Z')
+      );
+    });
+
+    it('requires an example for a pattern', () => {
+      expect(() =>
+        fromJsonSchema({
+          type: 'object',
+          properties: { code: { type: 'string', pattern: '^[a-z]{5}$' } },
+          required: ['code'],
+        })
+      ).to.throw(/'\$\.code' as its pattern has no example/);
+    });
+
+    it('names properties the same way as the core', () => {
+      expect(
+        fromJsonSchema(
+          {
+            type: 'object',
+            properties: { 'x-request-id': { type: 'string' } },
+          },
+          { '$.x-request-id': MatchersV3.uuid() }
+        )
+      ).to.have.property('x-request-id');
+    });
+
+    it('maps the array length to an array matcher', () => {
+      expect(
+        fromJsonSchema({
+          type: 'array',
+          items: { type: 'string', example: 'a' },
+          minItems: 2,
+          maxItems: 5,
+        })
+      ).to.deep.equal(
+        MatchersV3.constrainedArrayLike(MatchersV3.string('a'), 2, 5, 2)
+      );
+      expect(
+        fromJsonSchema({
+          type: 'array',
+          items: { type: 'integer', example: 1 },
+        })
+      ).to.deep.equal(MatchersV3.atLeastLike(MatchersV3.integer(1), 0, 1));
+    });
+
+    it('resolves local references and merges allOf', () => {
+      const schema: JsonSchema = {
+        $defs: {
+          named: {
+            type: 'object',
+            required: ['name'],
+            properties: { name: { type: 'string', example: 'Fred' } },
+          },
+        },
+        allOf: [
+          { $ref: '#/$defs/named' },
+          {
+            required: ['age'],
+            properties: { age: { type: 'integer', example: 30 } },
+          },
+        ],
+      };
+
+      expect(fromJsonSchema(schema)).to.deep.equal({
+        name: MatchersV3.string('Fred'),
+        age: MatchersV3.integer(30),
+      });
+    });
+
+    it('uses the first non-null variant of anyOf and oneOf', () => {
+      expect(
+        fromJsonSchema({
+          anyOf: [{ type: 'null' }, { type: 'string', example: 'x' }],
+        })
+      ).to.deep.equal(MatchersV3.string('x'));
+    });
+
+    it('uses the overrides for the matching paths', () => {
+      const schema: JsonSchema = {
+        type: 'object',
+        required: ['tags'],
+        properties: {
+          tags: { type: 'array', items: { type: 'string' } },
+          owner: { type: 'string' },
+        },
+      };
+
+      expect(
+        fromJsonSchema(schema, {
+          '$.tags[*]': MatchersV3.regex('[a-z]+', 'tag'),
+          '$.owner': MatchersV3.string('Fred'),
+        })
+      ).to.deep.equal({
+        tags: MatchersV3.atLeastLike(MatchersV3.regex('[a-z]+', 'tag'), 0, 1),
+        owner: MatchersV3.string('Fred'),
+      });
+    });
+
+    it('throws an exception for recursive and remote references', () => {
+      const recursive: JsonSchema = {
+        definitions: {
+          node: {
+            type: 'object',
+            required: ['child'],
+            properties: { child: { $ref: '#/definitions/node' } },
+          },
+        },
+        $ref: '#/definitions/node',
+      };
+
+      expect(() => fromJsonSchema(recursive)).to.throw(/is recursive/);
+      expect(() =>
+        fromJsonSchema({ $ref: 'http://example.com/schema.json' })
+      ).to.throw(/only local references are supported/);
+    });
+  });
+});
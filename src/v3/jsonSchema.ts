@@ -0,0 +1,377 @@
+import { AnyJson } from '../common/jsonTypes';
+import ConfigurationError from '../errors/configurationError';
+import {
+  atLeastLike,
+  boolean,
+  constrainedArrayLike,
+  date,
+  datetime,
+  equal,
+  integer,
+  like,
+  nullValue,
+  number,
+  propertyPath,
+  regex,
+  string,
+  time,
+  uuid,
+} from './matchers';
+import { escapeRegex } from './regex';
+
+export type JsonSchemaType =
+  | 'string'
+  | 'integer'
+  | 'number'
+  | 'boolean'
+  | 'null'
+  | 'object'
+  | 'array';
+
+/**
+ * The subset of a JSON Schema (draft-07 or 2020-12) document used to generate
+ * a matcher template. OpenAPI's `example` and `nullable` are also accepted.
+ */
+export interface JsonSchema {
+  $ref?: string;
+  $defs?: Record<string, JsonSchema>;
+  definitions?: Record<string, JsonSchema>;
+  type?: JsonSchemaType | JsonSchemaType[];
+  format?: string;
+  pattern?: string;
+  enum?: AnyJson[];
+  const?: AnyJson;
+  examples?: AnyJson[];
+  example?: AnyJson;
+  default?: AnyJson;
+  nullable?: boolean;
+  properties?: Record<string, JsonSchema>;
+  required?: string[];
+  items?: JsonSchema | JsonSchema[];
+  prefixItems?: JsonSchema[];
+  minItems?: number;
+  maxItems?: number;
+  allOf?: JsonSchema[];
+  anyOf?: JsonSchema[];
+  oneOf?: JsonSchema[];
+}
+
+/**
+ * Templates to use instead of the generated ones, keyed by the path of the
+ * value in the document (e.g. `$.owner.email` or `$.tags[*]`). An override
+ * for an optional property adds that property to the template.
+ */
+export type JsonSchemaOverrides = Record<string, unknown>;
+
+const EMAIL_REGEX = '^[^@\\s]+@[^@\\s]+\\.[^@\\s]+$';
+const URI_REGEX = '^[a-zA-Z][a-zA-Z0-9+.-]*:\\S+$';
+
+// Builds a SimpleDateFormat pattern for an RFC 3339 time, so that examples
+// with and without fractional seconds or an offset are both supported
+const rfc3339TimeFormat = (example: string): string => {
+  const fraction = /:\d{2}\.(\d+)/.exec(example);
+  const offset = /(Z|[+-]\d{2}:\d{2})$/i.test(example);
+
+  return `HH:mm:ss${fraction ? `.${'S'.repeat(fraction[1].length)}` : ''}${
+    offset ? 'XXX' : ''
+  }`;
+};
+
+const exampleFor = (schema: JsonSchema): AnyJson | undefined => {
+  if (schema.examples && schema.examples.length > 0) {
+    return schema.examples[0];
+  }
+  if (schema.example !== undefined) {
+    return schema.example;
+  }
+  if (schema.default !== undefined) {
+    return schema.default;
+  }
+  return schema.enum?.[0];
+};
+
+const typeOf = (schema: JsonSchema): JsonSchemaType | undefined => {
+  if (Array.isArray(schema.type)) {
+    // Nullable values are matched against their non-null type
+    return schema.type.find((t) => t !== 'null') || schema.type[0];
+  }
+  if (schema.type) {
+    return schema.type;
+  }
+  if (schema.properties) {
+    return 'object';
+  }
+  if (schema.items || schema.prefixItems) {
+    return 'array';
+  }
+  return undefined;
+};
+
+class TemplateGenerator {
+  private refs: string[] = [];
+
+  constructor(
+    private readonly root: object,
+    private readonly overrides: JsonSchemaOverrides
+  ) {}
+
+  public generate(schema: JsonSchema, path: string): unknown {
+    if (path in this.overrides) {
+      return this.overrides[path];
+    }
+    if (schema.$ref) {
+      return this.generateRef(schema.$ref, path);
+    }
+    if (schema.allOf) {
+      return this.generate(this.mergeAllOf(schema), path);
+    }
+
+    const { anyOf, oneOf, ...rest } = schema;
+    const variants = anyOf || oneOf;
+    if (variants && variants.length > 0) {
+      // Matchers can't express alternatives, so the first non-null variant
+      // is used as the template
+      const variant =
+        variants.find((v) => typeOf(v) !== 'null') || variants[0];
+      return this.generate({ ...rest, ...variant }, path);
+    }
+
+    if (schema.const !== undefined) {
+      return equal(schema.const);
+    }
+    if (schema.enum) {
+      return this.generateEnum(schema.enum, exampleFor(schema), path);
+    }
+
+    const example = exampleFor(schema);
+
+    switch (typeOf(schema)) {
+      case 'string':
+        return this.generateString(
+          schema,
+          example as string | undefined,
+          path
+        );
+      case 'integer':
+        return integer(example as number | undefined);
+      case 'number':
+        return number(example as number | undefined);
+      case 'boolean':
+        return boolean(example as boolean | undefined);
+      case 'null':
+        return nullValue();
+      case 'object':
+        return this.generateObject(schema, example, path);
+      case 'array':
+        return this.generateArray(schema, example, path);
+      default:
+        if (example !== undefined) {
+          return like(example);
+        }
+        throw new ConfigurationError(
+          `Unable to generate a template for '${path}' as the schema has no type or example`
+        );
+    }
+  }
+
+  private generateRef(ref: string, path: string): unknown {
+    if (!ref.startsWith('#')) {
+      throw new ConfigurationError(
+        `Unable to resolve '${ref}' at '${path}': only local references are supported`
+      );
+    }
+    if (this.refs.includes(ref)) {
+      throw new ConfigurationError(
+        `Unable to generate a template for '${path}' as '${ref}' is recursive. Provide an override for this path`
+      );
+    }
+
+    this.refs.push(ref);
+    try {
+      return this.generate(this.resolve(ref), path);
+    } finally {
+      this.refs.pop();
+    }
+  }
+
+  private resolve(ref: string): JsonSchema {
+    const resolved = ref
+      .replace(/^#\/?/, '')
+      .split('/')
+      .filter((segment) => segment !== '')
+      .map((segment) =>
+        decodeURIComponent(segment).replace(/~1/g, '/').replace(/~0/g, '~')
+      )
+      .reduce<unknown>(
+        (node, segment) =>
+          node && typeof node === 'object'
+            ? (node as Record<string, unknown>)[segment]
+            : undefined,
+        this.root
+      );
+
+    if (!resolved || typeof resolved !== 'object') {
+      throw new ConfigurationError(`Unable to resolve the reference '${ref}'`);
+    }
+
+    return resolved as JsonSchema;
+  }
+
+  private mergeAllOf(schema: JsonSchema): JsonSchema {
+    const { allOf = [], ...rest } = schema;
+
+    return allOf
+      .map((s) => (s.$ref ? this.resolve(s.$ref) : s))
+      .map((s) => (s.allOf ? this.mergeAllOf(s) : s))
+      .reduce((merged: JsonSchema, s) => {
+        const result = { ...merged, ...s };
+
+        if (merged.properties || s.properties) {
+          result.properties = { ...merged.properties, ...s.properties };
+        }
+        if (merged.required || s.required) {
+          result.required = [...(merged.required || []), ...(s.required || [])];
+        }
+
+        return result;
+      }, rest);
+  }
+
+  private generateEnum(
+    values: AnyJson[],
+    example: AnyJson | undefined,
+    path: string
+  ): unknown {
+    if (values.length === 0) {
+      throw new ConfigurationError(`The enum at '${path}' has no values`);
+    }
+    if (values.every((v) => typeof v === 'string')) {
+      return regex(
+        `^(?:${(values as string[]).map(escapeRegex).join('|')})$`,
+        (example ?? values[0]) as string
+      );
+    }
+
+    return equal(example ?? values[0]);
+  }
+
+  private generateString(
+    schema: JsonSchema,
+    example: string | undefined,
+    path: string
+  ): unknown {
+    if (schema.pattern) {
+      // A generated example would be different on every run, changing the
+      // pact file each time, so one must be given
+      if (example === undefined) {
+        throw new ConfigurationError(
+          `Unable to generate a template for '${path}' as its pattern has no example. Add an 'example' to the schema, or provide an override for this path`
+        );
+      }
+      return regex(schema.pattern, example);
+    }
+
+    switch (schema.format) {
+      case 'date-time': {
+        const value = example ?? '2000-01-01T00:00:00.000Z';
+        const [, timePart = ''] = value.split(/t/i);
+
+        return datetime(
+          `yyyy-MM-dd'T'${rfc3339TimeFormat(timePart)}`,
+          value
+        );
+      }
+      case 'date':
+        return date('yyyy-MM-dd', example ?? '2000-01-01');
+      case 'time': {
+        const value = example ?? '00:00:00';
+
+        return time(rfc3339TimeFormat(value), value);
+      }
+      case 'uuid':
+        return uuid(example);
+      case 'email':
+        return regex(EMAIL_REGEX, example ?? 'user@example.com');
+      case 'uri':
+        return regex(URI_REGEX, example ?? 'http://example.com');
+      default:
+        return string(example);
+    }
+  }
+
+  private generateObject(
+    schema: JsonSchema,
+    example: AnyJson | undefined,
+    path: string
+  ): unknown {
+    if (!schema.properties) {
+      return like(example ?? {});
+    }
+
+    const required = schema.required || [];
+
+    return Object.keys(schema.properties).reduce((template, name) => {
+      const propertyPathName = propertyPath(path, name);
+
+      // Optional properties are left out, as the provider may not return them
+      if (!required.includes(name) && !(propertyPathName in this.overrides)) {
+        return template;
+      }
+
+      return {
+        ...template,
+        [name]: this.generate(
+          (schema.properties as Record<string, JsonSchema>)[name],
+          propertyPathName
+        ),
+      };
+    }, {});
+  }
+
+  private generateArray(
+    schema: JsonSchema,
+    example: AnyJson | undefined,
+    path: string
+  ): unknown {
+    const tuple =
+      schema.prefixItems || (Array.isArray(schema.items) ? schema.items : null);
+
+    if (tuple) {
+      return tuple.map((s, i) => this.generate(s, `${path}[${i}]`));
+    }
+    if (!schema.items) {
+      return like(example ?? []);
+    }
+
+    const template = this.generate(schema.items as JsonSchema, `${path}[*]`);
+    const min = schema.minItems ?? 0;
+    const count = Math.max(min, 1);
+
+    if (schema.maxItems !== undefined) {
+      return constrainedArrayLike(template, min, schema.maxItems, count);
+    }
+
+    return atLeastLike(template, min, count);
+  }
+}
+
+/**
+ * Generates a matcher template from a JSON Schema (draft-07 or 2020-12).
+ *
+ * Types are mapped to type matchers, and the `date-time`, `date`, `time`,
+ * `uuid`, `email` and `uri` formats to the equivalent matchers. `pattern` is
+ * mapped to a regex matcher, which requires an example, string enums to a regex of the allowed values,
+ * and `minItems`/`maxItems` to the array length. Example values are taken from
+ * `examples`, `example` or `default`. Only required properties are included.
+ *
+ * @param schema JSON Schema to generate the template from
+ * @param overrides Templates to use for specific paths instead, e.g. `{ '$.id': integer(10) }`
+ * @param root Document to resolve `$ref`s against, if the schema is part of a larger document (e.g. an OpenAPI document). Defaults to the schema
+ */
+export function fromJsonSchema(
+  schema: JsonSchema,
+  overrides: JsonSchemaOverrides = {},
+  root: object = schema
+): unknown {
+  return new TemplateGenerator(root, overrides).generate(schema, '$');
+}
@@ -1,9 +1,22 @@
 import * as chai from 'chai';
+import sinon from 'sinon';
 import * as MatchersV3 from './matchers';
+import * as Generators from './generators';
+import logger from '../common/logger';
 
 const { expect } = chai;
 
 describe('V3 Matchers', () => {
+  let warn: sinon.SinonStub;
+
+  beforeEach(() => {
+    warn = sinon.stub(logger, 'warn');
+  });
+
+  afterEach(() => {
+    warn.restore();
+  });
+
   it('compiles with nested examples from issue 1054', () => {
     interface Foo {
       a: string;
@@ -273,6 +286,12 @@ describe('V3 Matchers', () => {
         'constrainedArrayLike has a maximum of 6 but 8 elements where requested. Make sure the count is less than or equal to the max.'
       );
     });
+
+    it('throws an error if the minimum is more than the maximum', () => {
+      expect(() => MatchersV3.constrainedArrayLike({ a: 'b' }, 4, 2)).to.throw(
+        /has a minimum of 4 and a maximum of 2/
+      );
+    });
   });
 
   describe('#integer', () => {
@@ -304,6 +323,11 @@ describe('V3 Matchers', () => {
         });
       });
     });
+
+    it('throws an error if the example is not an integer', () => {
+      expect(() => MatchersV3.integer(1.5)).to.throw(/not an integer/);
+      expect(() => MatchersV3.integer(NaN)).to.throw(/not an integer/);
+    });
   });
 
   describe('#decimal', () => {
@@ -398,6 +422,12 @@ describe('V3 Matchers', () => {
         });
       });
     });
+
+    it('throws an error if the example does not match the regular expression', () => {
+      expect(() => MatchersV3.regex('^\\d+$', '12a')).to.throw(
+        "regex: Example value '12a' does not match the regular expression '^\\d+$'"
+      );
+    });
   });
 
   describe('#equal', () => {
@@ -424,6 +454,28 @@ describe('V3 Matchers', () => {
         });
       });
     });
+
+    it('logs a warning if the example does not match the format', () => {
+      MatchersV3.datetime(
+        "yyyy-MM-dd'T'HH:mm:ss.SSSX",
+        '2016-02-11 09:46:56.023Z'
+      );
+      MatchersV3.datetime("yyyy-MM-dd'T'HH:mm:ss", '2016-02-30T09:46:56');
+
+      expect(warn.callCount).to.eq(2);
+      expect(warn.firstCall.args[0]).to.match(/does not match the format/);
+      expect(warn.secondCall.args[0]).to.match(/does not match the format/);
+    });
+
+    it('logs a warning if the format is not a valid date format', () => {
+      const result = MatchersV3.datetime(
+        'yyyy-MM-ddTHH:mm:ss',
+        '2016-02-11T09:46:56'
+      );
+
+      expect(result.format).to.eq('yyyy-MM-ddTHH:mm:ss');
+      expect(warn.firstCall.args[0]).to.match(/illegal pattern character 'T'/);
+    });
   });
 
   describe('#time', () => {
@@ -436,6 +488,14 @@ describe('V3 Matchers', () => {
         value: '09:46:56',
       });
     });
+
+    it('logs a warning if the example is not a valid time', () => {
+      MatchersV3.time('HH:mm:ss', '24:46:56');
+
+      expect(warn.firstCall.args[0]).to.match(
+        /does not match the format 'HH:mm:ss'/
+      );
+    });
   });
 
   describe('#date', () => {
@@ -535,6 +595,49 @@ describe('V3 Matchers', () => {
     });
   });
 
+  describe('#mockServerUrl', () => {
+    it('returns a regex matcher and a MockServerURL generator', () => {
+      const result = MatchersV3.mockServerUrl(
+        'https://api.example.com/orders/1',
+        '.*(/orders/\\d+)$'
+      );
+      expect(result).to.deep.equal({
+        'pact:matcher:type': 'regex',
+        'pact:generator:type': 'MockServerURL',
+        regex: '.*(/orders/\\d+)$',
+        value: 'https://api.example.com/orders/1',
+        example: 'https://api.example.com/orders/1',
+      });
+    });
+
+    it('accepts a regular expression', () => {
+      expect(
+        MatchersV3.mockServerUrl(
+          'https://api.example.com/orders/1',
+          /.*(\/orders\/\d+)$/
+        )
+      ).to.include({ regex: '.*(\\/orders\\/\\d+)$' });
+    });
+
+    it('throws an exception if the example does not match the regular expression', () => {
+      expect(() =>
+        MatchersV3.mockServerUrl(
+          'https://api.example.com/customers/1',
+          '.*(/orders/\\d+)$'
+        )
+      ).to.throw(/does not match the regular expression/);
+    });
+
+    it('throws an exception if the regular expression has no group for the path', () => {
+      expect(() =>
+        MatchersV3.mockServerUrl(
+          'https://api.example.com/orders/1',
+          '.*/orders/\\d+$'
+        )
+      ).to.throw(/must have a group/);
+    });
+  });
+
   describe('#uuid', () => {
     it('returns a JSON representation of an regex matcher for UUIDs', () => {
       const result = MatchersV3.uuid('ba4bd1bc-5556-11eb-9286-d71bc5b507be');
@@ -564,6 +667,273 @@ describe('V3 Matchers', () => {
     });
   });
 
+  describe('format matchers', () => {
+    const formats: Array<{
+      name: string;
+      matcher: (example?: string) => MatchersV3.V3RegexMatcher;
+      valid: string[];
+      invalid: string[];
+    }> = [
+      {
+        name: 'email',
+        matcher: MatchersV3.email,
+        valid: ['hello@pact.io', 'first.last+tag@example.co.uk'],
+        invalid: ['hello@pact', 'hello@@pact.io', 'hello'],
+      },
+      {
+        name: 'ipv4Address',
+        matcher: MatchersV3.ipv4Address,
+        valid: ['127.0.0.13', '255.255.255.255', '0.0.0.0'],
+        invalid: ['256.0.0.1', '1.2.3', '1.2.3.4.5'],
+      },
+      {
+        name: 'ipv6Address',
+        matcher: MatchersV3.ipv6Address,
+        valid: ['::ffff:192.0.2.128', '2001:db8::1', '::1'],
+        invalid: ['2001:db8', 'gggg::1'],
+      },
+      {
+        name: 'hexadecimal',
+        matcher: MatchersV3.hexadecimal,
+        valid: ['3F', 'deadBEEF01'],
+        invalid: ['0x3F', 'xyz'],
+      },
+      {
+        name: 'semver',
+        matcher: MatchersV3.semver,
+        valid: ['1.0.0', '1.2.3-beta.1+build.5', '0.0.1-alpha'],
+        invalid: ['1.2', '01.2.3', '1.2.3-01', 'v1.2.3'],
+      },
+      {
+        name: 'base64',
+        matcher: MatchersV3.base64,
+        valid: ['cGFjdA==', 'YWJj', 'YWI='],
+        invalid: ['', 'cGFjdA=', 'abc', 'a==='],
+      },
+      {
+        name: 'jwt',
+        matcher: MatchersV3.jwt,
+        valid: ['eyJhbGciOiJub25lIn0.eyJzdWIiOiIxIn0.', 'a.b.c'],
+        invalid: ['a.b', 'a.b.c.d', 'a+b.c.d'],
+      },
+      {
+        name: 'iso8601Duration',
+        matcher: MatchersV3.iso8601Duration,
+        valid: ['P1DT12H', 'P1Y2M10DT2H30M', 'PT0.5S', 'P3W'],
+        invalid: ['1D', 'P1H', 'PT1D', 'P', 'PT', 'P1DT', 'P1D1Y'],
+      },
+      {
+        name: 'countryCode',
+        matcher: MatchersV3.countryCode,
+        valid: ['AU', 'NZ'],
+        invalid: ['au', 'AUS'],
+      },
+      {
+        name: 'currencyCode',
+        matcher: MatchersV3.currencyCode,
+        valid: ['AUD', 'EUR'],
+        invalid: ['AU', 'aud'],
+      },
+      {
+        name: 'e164Phone',
+        matcher: MatchersV3.e164Phone,
+        valid: ['+61491570006', '+14155552671'],
+        invalid: ['0491570006', '+0123', '+1234567890123456'],
+      },
+      {
+        name: 'mimeType',
+        matcher: MatchersV3.mimeType,
+        valid: [
+          'application/json',
+          'application/vnd.api+json',
+          'text/plain; charset=utf-8',
+        ],
+        invalid: ['json', 'text/', '/plain'],
+      },
+    ];
+
+    formats.forEach(({ name, matcher, valid, invalid }) => {
+      describe(`#${name}`, () => {
+        it('returns a regex matcher with a default example that matches', () => {
+          const result = matcher();
+
+          expect(result['pact:matcher:type']).to.eq('regex');
+          expect(result.value).to.match(new RegExp(result.regex));
+        });
+
+        it('accepts valid examples', () => {
+          valid.forEach((example) => {
+            const result = matcher(example);
+
+            expect(result.value).to.eq(example);
+            expect(example).to.match(new RegExp(result.regex));
+          });
+        });
+
+        it('throws an exception for invalid examples', () => {
+          invalid.forEach((example) => {
+            expect(() => matcher(example), example).to.throw(
+              /does not match the regular expression/
+            );
+          });
+        });
+      });
+    });
+
+    it('sets up a generator for hexadecimal values if no example is provided', () => {
+      expect(MatchersV3.hexadecimal()).to.include({
+        'pact:generator:type': 'RandomHexadecimal',
+        digits: 10,
+      });
+    });
+  });
+
+  describe('#notEmpty', () => {
+    it('returns a JSON representation of a notEmpty matcher', () => {
+      expect(MatchersV3.notEmpty(['a'])).to.deep.equal({
+        'pact:matcher:type': 'notEmpty',
+        value: ['a'],
+      });
+    });
+
+    it('throws an exception if the example is empty', () => {
+      expect(() => MatchersV3.notEmpty('')).to.throw(/is empty/);
+      expect(() => MatchersV3.notEmpty([])).to.throw(/is empty/);
+      expect(() => MatchersV3.notEmpty({})).to.throw(/is empty/);
+      expect(() => MatchersV3.notEmpty(null)).to.throw(/is empty/);
+    });
+  });
+
+  describe('#allOf', () => {
+    it('combines the matchers with AND logic', () => {
+      const pattern = MatchersV3.regex('^\\w+$', 'abc');
+      const nonEmpty = MatchersV3.notEmpty('abc');
+
+      expect(MatchersV3.allOf(pattern, nonEmpty)).to.deep.equal({
+        'pact:matcher:type': [pattern, nonEmpty],
+        value: 'abc',
+      });
+    });
+
+    it('throws an exception if the example does not satisfy all of the matchers', () => {
+      expect(() =>
+        MatchersV3.allOf(
+          MatchersV3.string('abc'),
+          MatchersV3.regex('\\d+', '1')
+        )
+      ).to.throw(/does not satisfy all of the matchers/);
+    });
+
+    it('throws an exception if no matchers are given', () => {
+      expect(() => MatchersV3.allOf()).to.throw();
+    });
+
+    it('can not be nested', () => {
+      const nested = MatchersV3.allOf(MatchersV3.string('a'));
+
+      expect(() =>
+        MatchersV3.allOf(nested as unknown as MatchersV3.Matcher<string>)
+      ).to.throw(/can not be nested/);
+    });
+  });
+
+  describe('#withGenerator', () => {
+    it('adds the generator and its attributes to the matcher', () => {
+      const result = MatchersV3.withGenerator(
+        MatchersV3.integer(5),
+        Generators.randomInt(1, 100)
+      );
+
+      expect(result).to.deep.equal({
+        'pact:matcher:type': 'integer',
+        'pact:generator:type': 'RandomInt',
+        value: 5,
+        min: 1,
+        max: 100,
+      });
+    });
+
+    it('replaces a generator that the matcher already has', () => {
+      const result = MatchersV3.withGenerator(
+        MatchersV3.datetime("yyyy-MM-dd'T'HH:mm:ss", '2020-01-01T10:00:00'),
+        Generators.datetime(undefined, '+ 1 day')
+      );
+
+      expect(result).to.include({
+        'pact:matcher:type': 'timestamp',
+        'pact:generator:type': 'DateTime',
+        format: "yyyy-MM-dd'T'HH:mm:ss",
+        expression: '+ 1 day',
+      });
+    });
+
+    it('throws an exception if the generator attributes conflict with the matcher', () => {
+      expect(() =>
+        MatchersV3.withGenerator(
+          MatchersV3.eachLike(1, 2),
+          Generators.randomInt(1, 100)
+        )
+      ).to.throw(/different values for 'min'/);
+    });
+  });
+
+  describe('#validateTemplate', () => {
+    it('accepts a template with valid examples', () => {
+      expect(() =>
+        MatchersV3.validateTemplate({
+          id: MatchersV3.integer(1),
+          items: MatchersV3.eachLike({
+            created: MatchersV3.date('yyyy-MM-dd', '2020-01-01'),
+          }),
+        })
+      ).not.to.throw();
+    });
+
+    it('throws an error with the path of the invalid matcher', () => {
+      const quantity = MatchersV3.integer(1);
+      quantity.value = 1.5;
+
+      expect(() =>
+        MatchersV3.validateTemplate({
+          order: { items: MatchersV3.eachLike({ quantity }) },
+        })
+      ).to.throw(
+        "The 'integer' matcher at '$.order.items[*].quantity' is invalid: Example value '1.5' is not an integer"
+      );
+    });
+
+    it('logs a warning with the path of an invalid date', () => {
+      const created = MatchersV3.date('yyyy-MM-dd', '2020-01-01');
+      created.value = '01/01/2020';
+
+      MatchersV3.validateTemplate({
+        order: { items: MatchersV3.eachLike({ created }) },
+      });
+
+      expect(warn.firstCall.args[0]).to.eq(
+        "The 'date' matcher at '$.order.items[*].created' is invalid: Example value '01/01/2020' does not match the format 'yyyy-MM-dd'"
+      );
+    });
+
+    it('checks the example arrays against the minimum and maximum', () => {
+      expect(() =>
+        MatchersV3.validateTemplate({
+          'item ids': { 'pact:matcher:type': 'type', min: 2, value: [1] },
+        })
+      ).to.throw(/at '\$\['item ids'\]'.*less than the minimum of 2/);
+    });
+  });
+
+  describe('#eachValueMatches', () => {
+    it('throws an error if a value of the example does not match the rules', () => {
+      expect(() =>
+        MatchersV3.eachValueMatches({ a: 'abc', b: '123' }, [
+          MatchersV3.regex(/^[a-z]+$/, 'abc'),
+        ])
+      ).to.throw(/The value of 'b' in the example does not match the rules/);
+    });
+  });
+
   describe('#reify', () => {
     describe('when given an object with no matchers', () => {
       const object = {
@@ -715,5 +1085,111 @@ describe('V3 Matchers', () => {
         });
       });
     });
+
+    describe('when given a combined matcher', () => {
+      it('returns the example value', () => {
+        const result: string = MatchersV3.reify(
+          MatchersV3.allOf(
+            MatchersV3.regex('^\\w+$', 'abc'),
+            MatchersV3.notEmpty('abc')
+          )
+        );
+
+        expect(result).to.eq('abc');
+      });
+    });
+
+    describe('when given a template built from matchers', () => {
+      interface Project {
+        id: number;
+        name: string;
+        due: string;
+        tags: string[];
+        owner: { email: string; active: boolean };
+      }
+
+      it('returns a value with the type of the example', () => {
+        const template = {
+          id: MatchersV3.integer(1),
+          name: MatchersV3.like('Project 1'),
+          due: MatchersV3.datetime(
+            "yyyy-MM-dd'T'HH:mm:ss",
+            '2020-01-01T10:00:00'
+          ),
+          tags: MatchersV3.eachLike(MatchersV3.regex('[a-z]+', 'tag')),
+          owner: MatchersV3.like({
+            email: 'fred@example.com',
+            active: MatchersV3.boolean(true),
+          }),
+        };
+
+        // Fails to compile if the reified type drifts from the Project type
+        const project: Project = MatchersV3.reify(template);
+
+        expect(project).to.deep.equal({
+          id: 1,
+          name: 'Project 1',
+          due: '2020-01-01T10:00:00',
+          tags: ['tag'],
+          owner: { email: 'fred@example.com', active: true },
+        });
+      });
+    });
+  });
+
+  describe('#describeTemplate', () => {
+    it('lists each matcher with the path the core applies it to', () => {
+      const template = {
+        id: MatchersV3.integer(),
+        'first name': MatchersV3.string('Fred'),
+        items: MatchersV3.eachLike(
+          { sku: MatchersV3.regex('^[A-Z]{3}$', 'ABC') },
+          2
+        ),
+        prices: MatchersV3.eachValueMatches({ ABC: 1.5 }, [
+          MatchersV3.number(1.5),
+        ]),
+        attributes: MatchersV3.eachKeyLike('colour', {
+          value: MatchersV3.string('red'),
+        }),
+      };
+
+      expect(MatchersV3.describeTemplate(template)).to.deep.equal([
+        {
+          path: '$.id',
+          matcherType: 'integer',
+          example: 101,
+          generator: 'RandomInt',
+        },
+        { path: "$['first name']", matcherType: 'type', example: 'Fred' },
+        {
+          path: '$.items',
+          matcherType: 'type',
+          example: [{ sku: 'ABC' }, { sku: 'ABC' }],
+        },
+        { path: '$.items[*].sku', matcherType: 'regex', example: 'ABC' },
+        { path: '$.prices', matcherType: 'eachValue', example: { ABC: 1.5 } },
+        {
+          path: '$.attributes',
+          matcherType: 'values',
+          example: { colour: { value: 'red' } },
+        },
+        { path: '$.attributes.*.value', matcherType: 'type', example: 'red' },
+      ]);
+    });
+
+    it('lists each matcher of a combined matcher at the same path', () => {
+      expect(
+        MatchersV3.describeTemplate({
+          name: MatchersV3.allOf(
+            MatchersV3.regex('^\\w+$', 'abc'),
+            MatchersV3.notEmpty('abc')
+          ),
+        })
+      ).to.deep.equal([
+        { path: '$.name', matcherType: 'regex', example: 'abc' },
+        { path: '$.name', matcherType: 'notEmpty', example: 'abc' },
+      ]);
+    });
   });
 });
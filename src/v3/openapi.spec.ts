@@ -0,0 +1,233 @@
+import * as chai from 'chai';
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import * as MatchersV3 from './matchers';
+import {
+  interactionFromOpenApi,
+  loadOpenApiDocument,
+  OpenApiDocument,
+} from './openapi';
+
+const { expect } = chai;
+
+const document: OpenApiDocument = {
+  openapi: '3.0.3',
+  paths: {
+    '/projects/{projectId}/tasks': {
+      parameters: [
+        {
+          name: 'projectId',
+          in: 'path',
+          required: true,
+          schema: { type: 'integer', example: 10 },
+        },
+      ],
+      get: {
+        operationId: 'listTasks',
+        summary: 'a request for the tasks of a project',
+        parameters: [
+          {
+            name: 'status',
+            in: 'query',
+            required: true,
+            schema: { type: 'string', enum: ['open', 'done'] },
+          },
+          { name: 'page', in: 'query', schema: { type: 'integer' } },
+          {
+            name: 'X-Request-Id',
+            in: 'header',
+            required: true,
+            schema: { type: 'string', format: 'uuid' },
+          },
+        ],
+        responses: {
+          '200': {
+            content: {
+              'application/json': {
+                schema: {
+                  type: 'array',
+                  items: { $ref: '#/components/schemas/Task' },
+                },
+              },
+            },
+          },
+          '404': { $ref: '#/components/responses/NotFound' },
+        },
+      },
+      post: {
+        operationId: 'createTask',
+        requestBody: {
+          content: {
+            'application/json': {
+              schema: { $ref: '#/components/schemas/Task' },
+            },
+          },
+        },
+        responses: {
+          '201': {
+            headers: {
+              Location: { required: true, schema: { type: 'string' } },
+            },
+          },
+        },
+      },
+    },
+  },
+  components: {
+    schemas: {
+      Task: {
+        type: 'object',
+        required: ['id', 'name'],
+        properties: {
+          id: { type: 'integer', example: 1 },
+          name: { type: 'string', example: 'Task 1' },
+        },
+      },
+    },
+    responses: {
+      NotFound: { description: 'Not found' },
+    },
+  },
+};
+
+const task = {
+  id: MatchersV3.integer(1),
+  name: MatchersV3.string('Task 1'),
+};
+
+describe('OpenAPI interactions', () => {
+  describe('#interactionFromOpenApi', () => {
+    it('generates the request and response for the operation', () => {
+      const interaction = interactionFromOpenApi(document, 'listTasks', 200);
+
+      expect(interaction.uponReceiving).to.eq(
+        'a request for the tasks of a project'
+      );
+      expect(interaction.withRequest).to.deep.include({
+        method: 'GET',
+        path: MatchersV3.regex(
+          '^/projects/-?\\d+/tasks$',
+          '/projects/10/tasks'
+        ),
+        query: {
+          status: MatchersV3.regex('^(?:open|done)$', 'open'),
+        },
+      });
+      expect(interaction.withRequest.headers).to.have.all.keys('X-Request-Id');
+      expect(interaction.willRespondWith).to.deep.equal({
+        status: 200,
+        headers: { 'Content-Type': 'application/json' },
+        body: MatchersV3.atLeastLike(task, 0, 1),
+      });
+    });
+
+    it('generates header values that match the parameter schema', () => {
+      const interaction = interactionFromOpenApi(document, 'listTasks', 200);
+      const header = (interaction.withRequest.headers || {})[
+        'X-Request-Id'
+      ] as MatchersV3.V3RegexMatcher;
+
+      expect(header.value).to.match(new RegExp(header.regex));
+    });
+
+    it('uses a fixed value for a parameter without an example', () => {
+      const doc: OpenApiDocument = {
+        openapi: '3.0.3',
+        paths: {
+          '/tasks/{taskId}': {
+            get: {
+              operationId: 'getTask',
+              parameters: [
+                {
+                  name: 'taskId',
+                  in: 'path',
+                  required: true,
+                  schema: { type: 'integer' },
+                },
+              ],
+              responses: { '200': {} },
+            },
+          },
+        },
+      };
+
+      expect(
+        interactionFromOpenApi(doc, 'getTask', 200).withRequest.path
+      ).to.deep.eq(MatchersV3.regex('^/tasks/-?\\d+$', '/tasks/1'));
+    });
+
+    it('requires an example for a parameter with a pattern', () => {
+      const doc: OpenApiDocument = {
+        openapi: '3.0.3',
+        paths: {
+          '/tasks/{taskId}': {
+            get: {
+              operationId: 'getTask',
+              parameters: [
+                {
+                  name: 'taskId',
+                  in: 'path',
+                  required: true,
+                  schema: { type: 'string', pattern: '^T-\\d{4}$' },
+                },
+              ],
+              responses: { '200': {} },
+            },
+          },
+        },
+      };
+
+      expect(() => interactionFromOpenApi(doc, 'getTask', 200)).to.throw(
+        /'taskId' as its pattern has no example/
+      );
+    });
+
+    it('generates the request body and required response headers', () => {
+      const interaction = interactionFromOpenApi(document, 'createTask', 201, {
+        description: 'a request to create a task',
+      });
+
+      expect(interaction.uponReceiving).to.eq('a request to create a task');
+      expect(interaction.withRequest.contentType).to.eq('application/json');
+      expect(interaction.withRequest.body).to.deep.equal(task);
+      expect(interaction.willRespondWith.headers).to.deep.equal({
+        Location: MatchersV3.regex('^.*$', 'Location'),
+      });
+    });
+
+    it('resolves referenced responses', () => {
+      expect(
+        interactionFromOpenApi(document, 'listTasks', 404).willRespondWith
+      ).to.deep.equal({ status: 404 });
+    });
+
+    it('throws an error for unknown operations and status codes', () => {
+      expect(() =>
+        interactionFromOpenApi(document, 'deleteTask', 200)
+      ).to.throw(/no operation with the operationId 'deleteTask'/);
+      expect(() =>
+        interactionFromOpenApi(document, 'createTask', 500)
+      ).to.throw(/has no response for the status code 500/);
+    });
+  });
+
+  describe('#loadOpenApiDocument', () => {
+    it('loads a YAML document', () => {
+      const file = path.join(os.tmpdir(), `pact-openapi-${Date.now()}.yaml`);
+      fs.writeFileSync(
+        file,
+        ['openapi: 3.1.0', 'paths:', '  /status:', '    get: {}'].join('\n')
+      );
+
+      try {
+        expect(loadOpenApiDocument(file)).to.deep.equal({
+          openapi: '3.1.0',
+          paths: { '/status': { get: {} } },
+        });
+      } finally {
+        fs.unlinkSync(file);
+      }
+    });
+  });
+});
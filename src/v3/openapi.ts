@@ -0,0 +1,456 @@
+import * as fs from 'fs';
+import * as path from 'path';
+import { load as loadYaml } from 'js-yaml';
+import ConfigurationError from '../errors/configurationError';
+import { fromJsonSchema, JsonSchema, JsonSchemaOverrides } from './jsonSchema';
+import { regex } from './matchers';
+import { escapeRegex, stripAnchors } from './regex';
+import {
+  Path,
+  TemplateHeaders,
+  TemplateQuery,
+  V3Interaction,
+  V3ProviderState,
+  V3Request,
+  V3Response,
+} from './types';
+
+const HTTP_METHODS = [
+  'get',
+  'put',
+  'post',
+  'delete',
+  'options',
+  'head',
+  'patch',
+  'trace',
+] as const;
+
+const UUID_REGEX =
+  '[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}';
+
+interface OpenApiReference {
+  $ref?: string;
+}
+
+interface OpenApiParameter extends OpenApiReference {
+  name: string;
+  in: 'path' | 'query' | 'header' | 'cookie';
+  required?: boolean;
+  schema?: JsonSchema;
+  example?: unknown;
+  examples?: Record<string, { value?: unknown }>;
+}
+
+interface OpenApiMediaType {
+  schema?: JsonSchema;
+  example?: unknown;
+}
+
+interface OpenApiBody extends OpenApiReference {
+  required?: boolean;
+  content?: Record<string, OpenApiMediaType>;
+}
+
+interface OpenApiResponse extends OpenApiBody {
+  headers?: Record<string, Omit<OpenApiParameter, 'name' | 'in'>>;
+}
+
+interface OpenApiOperation {
+  operationId?: string;
+  summary?: string;
+  parameters?: OpenApiParameter[];
+  requestBody?: OpenApiBody;
+  responses?: Record<string, OpenApiResponse>;
+}
+
+type HttpMethod = typeof HTTP_METHODS[number];
+
+type OpenApiPathItem = Partial<Record<HttpMethod, OpenApiOperation>> & {
+  parameters?: OpenApiParameter[];
+};
+
+/**
+ * An OpenAPI 3.x document. Only the parts used to generate interactions are
+ * described here
+ */
+export interface OpenApiDocument {
+  openapi: string;
+  paths?: Record<string, OpenApiPathItem>;
+  components?: Record<string, unknown>;
+}
+
+export interface OpenApiInteractionOptions {
+  /**
+   * Description of the interaction. Defaults to the summary of the operation,
+   * or its operationId
+   */
+  description?: string;
+  /**
+   * Provider states for the interaction
+   */
+  states?: V3ProviderState[];
+  /**
+   * Media type of the request and response bodies. Defaults to
+   * application/json if the operation supports it, otherwise the first one
+   */
+  mediaType?: string;
+  /**
+   * Templates to use for parts of the request body, see {@link fromJsonSchema}
+   */
+  requestBodyOverrides?: JsonSchemaOverrides;
+  /**
+   * Templates to use for parts of the response body, see {@link fromJsonSchema}
+   */
+  responseBodyOverrides?: JsonSchemaOverrides;
+}
+
+const resolve = <T extends OpenApiReference>(
+  document: OpenApiDocument,
+  value: T,
+  seen: string[] = []
+): T => {
+  if (!value.$ref) {
+    return value;
+  }
+  if (!value.$ref.startsWith('#/') || seen.includes(value.$ref)) {
+    throw new ConfigurationError(
+      `Unable to resolve the reference '${value.$ref}' in the OpenAPI document`
+    );
+  }
+
+  const resolved = value.$ref
+    .substring(2)
+    .split('/')
+    .map((segment) =>
+      decodeURIComponent(segment).replace(/~1/g, '/').replace(/~0/g, '~')
+    )
+    .reduce<unknown>(
+      (node, segment) =>
+        node && typeof node === 'object'
+          ? (node as Record<string, unknown>)[segment]
+          : undefined,
+      document
+    );
+
+  if (!resolved || typeof resolved !== 'object') {
+    throw new ConfigurationError(
+      `Unable to resolve the reference '${value.$ref}' in the OpenAPI document`
+    );
+  }
+
+  return resolve(document, resolved as T, [...seen, value.$ref]);
+};
+
+/**
+ * Loads an OpenAPI document from a JSON or YAML file
+ * @param file Path to the document
+ */
+export const loadOpenApiDocument = (file: string): OpenApiDocument => {
+  const content = fs.readFileSync(file, 'utf-8');
+  const isYaml = ['.yaml', '.yml'].includes(path.extname(file).toLowerCase());
+  const document = isYaml ? loadYaml(content) : JSON.parse(content);
+
+  if (!document || !`${document.openapi}`.startsWith('3.')) {
+    throw new ConfigurationError(
+      `'${file}' is not an OpenAPI 3.x document. Only OpenAPI 3.x is supported`
+    );
+  }
+
+  return document as OpenApiDocument;
+};
+
+const findOperation = (
+  document: OpenApiDocument,
+  operationId: string
+): {
+  method: string;
+  pathTemplate: string;
+  operation: OpenApiOperation;
+  parameters: OpenApiParameter[];
+} => {
+  const paths = document.paths || {};
+
+  const found = Object.keys(paths)
+    .flatMap((pathTemplate) =>
+      HTTP_METHODS.filter((m) => paths[pathTemplate][m]).map((method) => ({
+        method,
+        pathTemplate,
+      }))
+    )
+    .find(
+      ({ pathTemplate, method }) =>
+        paths[pathTemplate][method]?.operationId === operationId
+    );
+
+  if (!found) {
+    throw new ConfigurationError(
+      `The OpenAPI document has no operation with the operationId '${operationId}'`
+    );
+  }
+
+  const { pathTemplate, method } = found;
+  const operation = paths[pathTemplate][method] as OpenApiOperation;
+
+  // Operation parameters replace path item parameters with the same name
+  const parameters = [
+    ...(paths[pathTemplate].parameters || []),
+    ...(operation.parameters || []),
+  ]
+    .map((p) => resolve(document, p))
+    .reduce(
+      (acc: OpenApiParameter[], p) => [
+        ...acc.filter((other) => other.name !== p.name || other.in !== p.in),
+        p,
+      ],
+      []
+    );
+
+  return { method: method.toUpperCase(), pathTemplate, operation, parameters };
+};
+
+// Regular expression for a parameter value, and an example that matches it
+const parameterMatcher = (
+  document: OpenApiDocument,
+  parameter: Omit<OpenApiParameter, 'in'>,
+  defaultPattern: string
+): { pattern: string; example: string } => {
+  const schema = resolve(document, parameter.schema || {});
+  const examples = Object.values(parameter.examples || {});
+  const example =
+    parameter.example ??
+    examples[0]?.value ??
+    schema.examples?.[0] ??
+    schema.example ??
+    schema.default ??
+    schema.enum?.[0];
+
+  if (example === undefined && schema.pattern) {
+    throw new ConfigurationError(
+      `Unable to generate a value for the parameter '${parameter.name}' as its pattern has no example. Add an 'example' to the parameter`
+    );
+  }
+
+  let pattern = defaultPattern;
+  let defaultExample = parameter.name;
+  if (schema.enum) {
+    pattern = `(?:${schema.enum.map((v) => escapeRegex(`${v}`)).join('|')})`;
+  } else if (schema.pattern) {
+    pattern = `(?:${stripAnchors(schema.pattern)})`;
+  } else if (schema.type === 'integer') {
+    pattern = '-?\\d+';
+    defaultExample = '1';
+  } else if (schema.type === 'number') {
+    pattern = '-?\\d+(?:\\.\\d+)?';
+    defaultExample = '1.5';
+  } else if (schema.type === 'boolean') {
+    pattern = '(?:true|false)';
+    defaultExample = 'true';
+  } else if (schema.format === 'uuid') {
+    pattern = UUID_REGEX;
+    defaultExample = 'ce118b6e-d8e1-11e7-9296-cec278b6b50a';
+  }
+
+  return {
+    pattern,
+    example: example !== undefined ? `${example}` : defaultExample,
+  };
+};
+
+const selectMediaType = (
+  content: Record<string, OpenApiMediaType> | undefined,
+  mediaType: string | undefined
+): string | undefined => {
+  if (!content) {
+    return undefined;
+  }
+
+  const mediaTypes = Object.keys(content);
+
+  if (mediaType) {
+    if (!mediaTypes.includes(mediaType)) {
+      throw new ConfigurationError(
+        `The operation does not support the media type '${mediaType}'`
+      );
+    }
+    return mediaType;
+  }
+
+  return mediaTypes.includes('application/json')
+    ? 'application/json'
+    : mediaTypes[0];
+};
+
+const bodyTemplate = (
+  document: OpenApiDocument,
+  content: OpenApiMediaType,
+  overrides: JsonSchemaOverrides = {}
+): unknown =>
+  fromJsonSchema(
+    {
+      ...(content.example !== undefined
+        ? { example: content.example as JsonSchema['example'] }
+        : {}),
+      ...content.schema,
+    },
+    overrides,
+    document
+  );
+
+const requestPath = (
+  document: OpenApiDocument,
+  pathTemplate: string,
+  parameters: OpenApiParameter[]
+): Path => {
+  const segments = pathTemplate.split(/(\{[^}]+\})/).filter((s) => s !== '');
+
+  if (!segments.some((s) => s.startsWith('{'))) {
+    return pathTemplate;
+  }
+
+  const parts = segments.map((segment) => {
+    const name = /^\{([^}]+)\}$/.exec(segment)?.[1];
+    if (!name) {
+      return { pattern: escapeRegex(segment), example: segment };
+    }
+
+    const parameter = parameters.find(
+      (p) => p.in === 'path' && p.name === name
+    ) || { name };
+
+    return parameterMatcher(document, parameter, '[^/]+');
+  });
+
+  return regex(
+    `^${parts.map((p) => p.pattern).join('')}$`,
+    parts.map((p) => p.example).join('')
+  );
+};
+
+const findResponse = (
+  document: OpenApiDocument,
+  operation: OpenApiOperation,
+  operationId: string,
+  status: number
+): OpenApiResponse => {
+  const responses = operation.responses || {};
+  const response =
+    responses[`${status}`] ||
+    responses[`${Math.floor(status / 100)}XX`] ||
+    responses.default;
+
+  if (!response) {
+    throw new ConfigurationError(
+      `The operation '${operationId}' has no response for the status code ${status}`
+    );
+  }
+
+  return resolve(document, response);
+};
+
+/**
+ * Generates an interaction for an operation in an OpenAPI 3.x document. The
+ * path, required query and header parameters, and the request and response
+ * bodies are pre-populated with matchers from the document, so a consumer test
+ * only needs to adjust the parts it uses.
+ *
+ * The interaction can be used with `PactV3.addInteraction`. For `PactV4`, see
+ * `addInteractionFromOpenApi`.
+ *
+ * @param document OpenAPI document, or the path to a JSON or YAML file containing it
+ * @param operationId operationId of the operation to generate the interaction for
+ * @param status Status code of the response to use
+ * @param options See {@link OpenApiInteractionOptions}
+ */
+export function interactionFromOpenApi(
+  document: OpenApiDocument | string,
+  operationId: string,
+  status: number,
+  options: OpenApiInteractionOptions = {}
+): V3Interaction {
+  const doc =
+    typeof document === 'string' ? loadOpenApiDocument(document) : document;
+  const { method, pathTemplate, operation, parameters } = findOperation(
+    doc,
+    operationId
+  );
+
+  const withRequest: V3Request = {
+    method,
+    path: requestPath(doc, pathTemplate, parameters),
+  };
+
+  const query: TemplateQuery = {};
+  const requestHeaders: TemplateHeaders = {};
+  parameters
+    .filter((p) => p.required && (p.in === 'query' || p.in === 'header'))
+    .forEach((p) => {
+      const { pattern, example } = parameterMatcher(doc, p, '.*');
+      const matcher = regex(`^${pattern}$`, example);
+
+      if (p.in === 'query') {
+        query[p.name] = matcher;
+      } else {
+        requestHeaders[p.name] = matcher;
+      }
+    });
+
+  const requestBody = operation.requestBody
+    ? resolve(doc, operation.requestBody)
+    : undefined;
+  const requestMediaType = selectMediaType(
+    requestBody?.content,
+    options.mediaType
+  );
+  if (requestBody?.content && requestMediaType) {
+    requestHeaders['Content-Type'] = requestMediaType;
+    withRequest.contentType = requestMediaType;
+    withRequest.body = bodyTemplate(
+      doc,
+      requestBody.content[requestMediaType],
+      options.requestBodyOverrides
+    );
+  }
+
+  const response = findResponse(doc, operation, operationId, status);
+  const willRespondWith: V3Response = { status };
+  const responseHeaders: TemplateHeaders = {};
+
+  Object.entries(response.headers || {})
+    .map(([name, header]) => ({ ...resolve(doc, header), name }))
+    .filter((header) => header.required)
+    .forEach((header) => {
+      const { pattern, example } = parameterMatcher(doc, header, '.*');
+      responseHeaders[header.name] = regex(`^${pattern}$`, example);
+    });
+
+  const responseMediaType = selectMediaType(
+    response.content,
+    options.mediaType
+  );
+  if (response.content && responseMediaType) {
+    responseHeaders['Content-Type'] = responseMediaType;
+    willRespondWith.body = bodyTemplate(
+      doc,
+      response.content[responseMediaType],
+      options.responseBodyOverrides
+    );
+  }
+
+  if (Object.keys(query).length > 0) {
+    withRequest.query = query;
+  }
+  if (Object.keys(requestHeaders).length > 0) {
+    withRequest.headers = requestHeaders;
+  }
+  if (Object.keys(responseHeaders).length > 0) {
+    willRespondWith.headers = responseHeaders;
+  }
+
+  return {
+    ...(options.states ? { states: options.states } : {}),
+    uponReceiving: options.description || operation.summary || operationId,
+    withRequest,
+    willRespondWith,
+  };
+}
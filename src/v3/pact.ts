@@ -16,12 +16,23 @@ import {
   V3Request,
   V3Response,
 } from './types';
-import { matcherValueOrString } from './matchers';
-import { filterMissingFeatureFlag, generateMockServerError } from './display';
+import {
+  contractMismatches,
+  filterMissingFeatureFlag,
+  generateMockServerError,
+} from './display';
+import ContractMismatchError from '../errors/contractMismatchError';
 import logger from '../common/logger';
 import {
-  contentTypeFromHeaders,
+  expectedRequests,
+  RecordedInteraction,
+  recordedRequest,
+  reportConsumerTest,
+} from '../reporters/consumer';
+import {
+  setRequestBody,
   setRequestDetails,
+  setResponseBody,
   setResponseDetails,
 } from './ffi';
 
@@ -44,6 +55,8 @@ export class PactV3 {
 
   private interaction: ConsumerInteraction;
 
+  private interactions: RecordedInteraction[] = [];
+
   constructor(opts: PactV3Options) {
     this.opts = opts;
     this.setup();
@@ -95,6 +108,10 @@ export class PactV3 {
 
   public uponReceiving(description: string): PactV3 {
     this.interaction = this.pact.newInteraction(description);
+    this.interactions.push({
+      description,
+      providerStates: this.states.map((s) => s.description),
+    });
     this.states.forEach((s) => {
       if (s.parameters) {
         this.interaction.givenWithParams(
@@ -109,15 +126,9 @@ export class PactV3 {
   }
 
   public withRequest(req: V3Request): PactV3 {
-    if (req.body) {
-      this.interaction.withRequestBody(
-        matcherValueOrString(req.body),
-        req.contentType ||
-          contentTypeFromHeaders(req.headers, 'application/json')
-      );
-    }
-
+    setRequestBody(this.interaction, req);
     setRequestDetails(this.interaction, req);
+    this.recordRequest(req, req.body);
     return this;
   }
 
@@ -129,6 +140,7 @@ export class PactV3 {
     const body = readBinaryData(file);
     this.interaction.withRequestBinaryBody(body, contentType);
     setRequestDetails(this.interaction, req);
+    this.recordRequest(req);
 
     return this;
   }
@@ -141,18 +153,13 @@ export class PactV3 {
   ): PactV3 {
     this.interaction.withRequestMultipartBody(contentType, file, mimePartName);
     setRequestDetails(this.interaction, req);
+    this.recordRequest(req);
     return this;
   }
 
   public willRespondWith(res: V3Response): PactV3 {
     setResponseDetails(this.interaction, res);
-    if (res.body) {
-      this.interaction.withResponseBody(
-        matcherValueOrString(res.body),
-        res.contentType ||
-          contentTypeFromHeaders(res.headers, 'application/json')
-      );
-    }
+    setResponseBody(this.interaction, res);
     this.states = [];
     return this;
   }
@@ -191,6 +198,7 @@ export class PactV3 {
       this.opts.tls
     );
     const server = { port, url: `${scheme}://${host}:${port}`, id: 'unknown' };
+    const started = new Date();
     let val: T | undefined;
     let error: Error | undefined;
 
@@ -203,25 +211,46 @@ export class PactV3 {
     const matchingResults = this.pact.mockServerMismatches(port);
     const errors = filterMissingFeatureFlag(matchingResults);
     const success = this.pact.mockServerMatchedSuccessfully(port);
+    const failed = !success && errors.length > 0;
+    const expected = expectedRequests(this.interactions);
+
+    const mismatches = failed
+      ? contractMismatches(matchingResults, this.opts.diff, expected)
+      : [];
+
+    await reportConsumerTest(
+      this.opts,
+      this.interactions,
+      started,
+      mismatches,
+      error
+    );
 
     // Scenario: Pact validation failed
-    if (!success && errors.length > 0) {
+    if (failed) {
       let errorMessage = 'Test failed for the following reasons:';
-      errorMessage += `\n\n  ${generateMockServerError(matchingResults, '\t')}`;
+      errorMessage += `\n\n  ${generateMockServerError(
+        matchingResults,
+        '\t',
+        this.opts.diff,
+        expected
+      )}`;
 
       this.cleanup(false, server);
 
-      // If the tests throws an error, we need to rethrow the error, but print out
-      // any additional mock server errors to help the user understand what happened
-      // (The proximate cause here is often the HTTP 500 from the mock server,
-      // where the HTTP client then throws)
+      // Print out the mock server errors to help the user understand what
+      // happened. If the test threw an error, we need to rethrow it (the
+      // proximate cause here is often the HTTP 500 from the mock server, where
+      // the HTTP client then throws)
+      logger.error(errorMessage);
       if (error) {
-        logger.error(errorMessage);
         throw error;
       }
 
       // Test didn't throw, so we need to ensure the test fails
-      return Promise.reject(new Error(errorMessage));
+      return Promise.reject(
+        new ContractMismatchError(errorMessage, mismatches)
+      );
     }
 
     // Scenario: test threw an error, but Pact validation was OK (error in client or test)
@@ -235,6 +264,13 @@ export class PactV3 {
     return val;
   }
 
+  private recordRequest(req: V3Request, body?: unknown) {
+    const interaction = this.interactions[this.interactions.length - 1];
+    if (interaction) {
+      interaction.request = recordedRequest(req, body);
+    }
+  }
+
   private cleanup(success: boolean, server: V3MockServer) {
     if (success) {
       this.pact.writePactFile(this.opts.dir || './pacts');
@@ -247,6 +283,7 @@ export class PactV3 {
   // (this.pact cannot be re-used between tests)
   private setup() {
     this.states = [];
+    this.interactions = [];
     this.pact = makeConsumerPact(
       this.opts.consumer,
       this.opts.provider,
@@ -0,0 +1,12 @@
+/**
+ * Escapes a string so it matches itself in a regular expression
+ */
+export const escapeRegex = (s: string): string =>
+  s.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+
+/**
+ * Removes the leading `^` and trailing `$` from a regular expression, so it
+ * can be embedded in a larger one
+ */
+export const stripAnchors = (pattern: string): string =>
+  pattern.replace(/^\^/, '').replace(/([^\\])\$$/, '$1');
@@ -1,4 +1,6 @@
 import { AnyJson, JsonMap } from '../common/jsonTypes';
+import { DiffOptions } from './diff';
+import { Reporter } from '../reporters/types';
 
 export enum SpecificationVersion {
   SPECIFICATION_VERSION_V2 = 3,
@@ -37,6 +39,16 @@ export interface Matcher<T> {
   value?: T | Record<string, T>;
 }
 
+/**
+ * Pact Generator. Generators replace the example value of a matcher with a
+ * generated one when the interaction is replayed (e.g. by the mock server, or
+ * during provider verification). Any other attributes configure the generator.
+ */
+export interface ValueGenerator {
+  'pact:generator:type': string;
+  [attribute: string]: unknown;
+}
+
 export interface V3RegexMatcher extends Matcher<string> {
   regex: string;
   example?: string;
@@ -72,6 +84,50 @@ export interface RulesMatcher<T> extends Matcher<T> {
   rules: Matcher<T>[];
 }
 
+/**
+ * Matcher that combines a number of matchers. The core adds a rule for each of
+ * them at the path of the matcher, and the value must match all of them.
+ */
+export interface CombinedMatcher<T> {
+  'pact:matcher:type': Matcher<T>[];
+  value?: T;
+}
+
+/**
+ * A matcher found in a template by `describeTemplate`, with the path the core
+ * applies it to (e.g. `$.items[*].id`)
+ */
+export interface MatcherDescription {
+  path: string;
+  matcherType: string;
+  example: AnyJson;
+  generator?: string;
+}
+
+/**
+ * The type of the example value produced by a matcher template, i.e. the type
+ * of `reify(template)`. Matchers are replaced by the type of their example,
+ * and arrays and objects are mapped recursively. Templates whose type is not
+ * known (`unknown` or `any`) reify to `AnyJson`.
+ *
+ * @example
+ * const body = { id: integer(1), tags: eachLike(string('a')) };
+ * type Body = Reified<typeof body>; // { id: number; tags: string[] }
+ */
+export type Reified<T> = unknown extends T
+  ? AnyJson
+  : T extends CombinedMatcher<infer V>
+  ? Reified<V>
+  : T extends RulesMatcher<infer V>
+  ? Record<string, Reified<V>>
+  : T extends Matcher<infer V>
+  ? Reified<V>
+  : T extends ReadonlyArray<infer E>
+  ? Reified<E>[]
+  : T extends object
+  ? { [K in keyof T]: Reified<T[K]> }
+  : T;
+
 /**
  * Options for the mock server
  */
@@ -112,6 +168,14 @@ export interface PactV3Options {
    * The host to run the mock service, defaults to 127.0.0.1
    */
   host?: string;
+  /**
+   * How body differences are shown when the mock server reports a mismatch
+   */
+  diff?: DiffOptions;
+  /**
+   * Reporters to send the result of each interaction to, e.g. `junitReporter`
+   */
+  reporters?: Reporter[];
 }
 
 export interface V3ProviderState {
@@ -130,6 +194,15 @@ export type TemplateQuery = Record<
   | Array<string | Matcher<string | number | boolean>>
 >;
 
+/**
+ * Fields of an application/x-www-form-urlencoded body. Repeated fields
+ * may be given as an array of values
+ */
+export type TemplateFormBody = Record<
+  string,
+  string | Matcher<string> | Array<string | Matcher<string>>
+>;
+
 export interface V3Interaction {
   states?: V3ProviderState[];
   uponReceiving: string;
@@ -109,4 +109,86 @@ describe('xml element', () => {
       expect(child.matcher?.['pact:matcher:type']).not.to.be.empty;
     });
   });
+
+  describe('setAttributes', () => {
+    it('accepts attribute values that are matchers', () => {
+      const id = MatchersV3.integer(1);
+      const xml = new XmlElement('project').setAttributes({ id, type: 'a' });
+
+      expect(xml).to.have.deep.property('attributes', { id, type: 'a' });
+    });
+
+    it('converts a Map of attributes into an object', () => {
+      const xml = new XmlElement('project').setAttributes(
+        new Map([['id', '1234']])
+      );
+
+      expect(xml).to.have.deep.property('attributes', { id: '1234' });
+    });
+
+    it('throws an error for attribute values that are not scalar', () => {
+      expect(() =>
+        new XmlElement('project').setAttributes({
+          ids: MatchersV3.eachLike(1) as unknown as string,
+        })
+      ).to.throw(/XML attribute 'ids'/);
+    });
+  });
+
+  describe('setNamespace', () => {
+    it('adds a namespace declaration to the attributes', () => {
+      const xml = new XmlElement('ns1:projects')
+        .setAttributes({ id: '1234' })
+        .setNamespace('ns1', 'http://some.namespace')
+        .setNamespace(undefined, 'http://default.namespace');
+
+      expect(xml).to.have.deep.property('attributes', {
+        id: '1234',
+        'xmlns:ns1': 'http://some.namespace',
+        xmlns: 'http://default.namespace',
+      });
+    });
+  });
+
+  describe('eachLike', () => {
+    it('adds the minimum and maximum to the matcher', () => {
+      const xml = new XmlElement('projects').eachLike(
+        'project',
+        {},
+        undefined,
+        { min: 2, max: 4 }
+      );
+
+      expect(xml.children[0]).to.include({
+        'pact:matcher:type': 'type',
+        examples: 2,
+        min: 2,
+        max: 4,
+      });
+    });
+
+    it('throws an error if the number of examples is outside the limits', () => {
+      const xml = new XmlElement('projects');
+
+      expect(() =>
+        xml.eachLike('project', {}, undefined, { min: 2, examples: 1 })
+      ).to.throw(/minimum of 2/);
+      expect(() =>
+        xml.eachLike('project', {}, undefined, { max: 2, examples: 3 })
+      ).to.throw(/maximum of 2/);
+    });
+  });
+
+  describe('arrayContaining', () => {
+    it('adds an arrayContains matcher with the variants', () => {
+      const book = new XmlElement('item').setAttributes({ type: 'book' });
+      const film = new XmlElement('item').setAttributes({ type: 'film' });
+      const xml = new XmlElement('items').arrayContaining(book, film);
+
+      expect(xml.children[0]).to.deep.equal({
+        'pact:matcher:type': 'arrayContains',
+        variants: [book, film],
+      });
+    });
+  });
 });
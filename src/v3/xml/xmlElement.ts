@@ -2,8 +2,21 @@ import { isMatcher } from '../matchers';
 import { Matcher } from '../types';
 import { XmlNode } from './xmlNode';
 import { XmlText } from './xmlText';
+import ConfigurationError from '../../errors/configurationError';
 
-export type XmlAttributes = Map<string, string>;
+export type XmlAttributeValue =
+  | string
+  | number
+  | boolean
+  | Matcher<string | number | boolean>;
+
+/**
+ * Attributes of an element. Values may be matchers. A `Map` is also accepted
+ * for backwards compatibility, but is converted into a plain object.
+ */
+export type XmlAttributes =
+  | Record<string, XmlAttributeValue>
+  | Map<string, XmlAttributeValue>;
 export type XmlCallback = (n: XmlElement) => void;
 
 const modifyElementWithCallback = (el: XmlElement, cb?: XmlCallback) => {
@@ -11,8 +24,36 @@ const modifyElementWithCallback = (el: XmlElement, cb?: XmlCallback) => {
     cb(el);
   }
 };
+
+const normaliseAttributes = (
+  attributes?: XmlAttributes
+): Record<string, XmlAttributeValue> => {
+  const normalised: Record<string, XmlAttributeValue> = {};
+
+  if (attributes instanceof Map) {
+    attributes.forEach((v, k) => {
+      normalised[k] = v;
+    });
+  } else {
+    Object.assign(normalised, attributes);
+  }
+
+  Object.keys(normalised).forEach((k) => {
+    const v = normalised[k];
+    const example = isMatcher(v) ? v.value : v;
+
+    if (!['string', 'number', 'boolean'].includes(typeof example)) {
+      throw new ConfigurationError(
+        `XML attribute '${k}' must be a string, number, boolean or a matcher of one`
+      );
+    }
+  });
+
+  return normalised;
+};
+
 export class XmlElement extends XmlNode {
-  private attributes: XmlAttributes;
+  private attributes: Record<string, XmlAttributeValue>;
 
   children: XmlNode[] = [];
 
@@ -27,7 +68,21 @@ export class XmlElement extends XmlNode {
   }
 
   public setAttributes(attributes: XmlAttributes): XmlElement {
-    this.attributes = attributes;
+    this.attributes = normaliseAttributes(attributes);
+
+    return this;
+  }
+
+  /**
+   * Declares an XML namespace on this element (via an `xmlns` attribute)
+   * @param prefix Namespace prefix to use for element and attribute names (e.g. `ns1` for `ns1:project`). If omitted, the namespace becomes the default namespace
+   * @param uri The namespace URI
+   */
+  public setNamespace(prefix: string | undefined, uri: string): XmlElement {
+    this.attributes = {
+      ...this.attributes,
+      [prefix ? `xmlns:${prefix}` : 'xmlns']: uri,
+    };
 
     return this;
   }
@@ -75,18 +130,53 @@ export class XmlElement extends XmlNode {
     return this;
   }
 
+  /**
+   * Creates a repeated element, where each element must match the configured element
+   * @param name Element name
+   * @param attributes Map of element attributes
+   * @param cb Callback to configure the element
+   * @param options Number of examples to generate, and the minimum and maximum number of elements allowed
+   */
   public eachLike(
     name: string,
     attributes: XmlAttributes,
     cb?: XmlCallback,
     options: EachLikeOptions = { examples: 1 }
   ): XmlElement {
+    const { min, max, examples = min ?? 1 } = options;
+    if (min !== undefined && examples < min) {
+      throw new ConfigurationError(
+        `eachLike for '${name}' has a minimum of ${min} but ${examples} examples were requested`
+      );
+    }
+    if (max !== undefined && examples > max) {
+      throw new ConfigurationError(
+        `eachLike for '${name}' has a maximum of ${max} but ${examples} examples were requested`
+      );
+    }
+
     const el = new XmlElement(name).setAttributes(attributes);
     modifyElementWithCallback(el, cb);
     this.children.push({
       'pact:matcher:type': 'type',
       value: el,
-      examples: options.examples,
+      examples,
+      ...(min !== undefined ? { min } : {}),
+      ...(max !== undefined ? { max } : {}),
+    });
+
+    return this;
+  }
+
+  /**
+   * Matches repeated elements against a number of variants. Matching is successful if
+   * each variant occurs at least once. Variants may contain matching rules.
+   * @param variants Elements to match, e.g. `new XmlElement('item').setAttributes({ type: 'book' })`
+   */
+  public arrayContaining(...variants: XmlElement[]): XmlElement {
+    this.children.push({
+      'pact:matcher:type': 'arrayContains',
+      variants,
     });
 
     return this;
@@ -0,0 +1,20 @@
+// The references added to each interaction, by its handle from the core. The
+// core keeps one value per comment key, so all of the references of an
+// interaction are set together.
+const referencesByHandle = new WeakMap<object, Record<string, string>>();
+
+/**
+ * Adds a reference (e.g. the ID of a ticket) to the comments of an interaction
+ * @param handle The interaction's handle from the core
+ * @param kind The kind of reference, e.g. `jira`
+ * @param value The reference
+ */
+export const addReference = (
+  handle: { setComment(key: string, value: string): unknown },
+  kind: string,
+  value: string
+): void => {
+  const references = { ...referencesByHandle.get(handle), [kind]: value };
+  referencesByHandle.set(handle, references);
+  handle.setComment('references', JSON.stringify(references));
+};
@@ -0,0 +1,174 @@
+import {
+  ConsumerInteraction,
+  ConsumerPact,
+} from '@pact-foundation/pact-core';
+import chai from 'chai';
+import sinon from 'sinon';
+import sinonChai from 'sinon-chai';
+import {
+  InteractionWithPluginResponse,
+  InteractionWithResponse,
+  UnconfiguredInteraction,
+} from '.';
+import { like, regex } from '../../v3/matchers';
+import { recordedInteractions } from '../reporting';
+import { V4UnconfiguredInteraction } from './types';
+
+chai.use(sinonChai);
+
+const { expect } = chai;
+
+describe('V4 HTTP interactions', () => {
+  let pact: ConsumerPact;
+  let interaction: ConsumerInteraction;
+
+  const opts = { consumer: 'consumer', provider: 'provider' };
+
+  beforeEach(() => {
+    pact = {} as ConsumerPact;
+    interaction = {
+      uponReceiving: sinon.stub(),
+      withRequest: sinon.stub(),
+      withQuery: sinon.stub(),
+      withRequestHeader: sinon.stub(),
+      withRequestBody: sinon.stub(),
+      setPending: sinon.stub(),
+      addTextComment: sinon.stub(),
+      setComment: sinon.stub(),
+    } as unknown as ConsumerInteraction;
+  });
+
+  const unconfigured = () =>
+    new UnconfiguredInteraction(pact, interaction, opts, sinon.stub());
+
+  describe('UnconfiguredInteraction', () => {
+    it('sets the method, path, query, headers and body of a complete request', () => {
+      const path = regex('/orders/\\d+', '/orders/1');
+      const body = { id: like(1) };
+
+      unconfigured().withCompleteRequest({
+        method: 'POST',
+        path,
+        query: { page: ['1', '2'] },
+        headers: { 'Content-Type': 'application/json' },
+        body,
+      });
+
+      expect(interaction.withRequest).to.have.been.calledWith(
+        'POST',
+        JSON.stringify(path)
+      );
+      expect(interaction.withQuery).to.have.been.calledWith('page', 0, '1');
+      expect(interaction.withQuery).to.have.been.calledWith('page', 1, '2');
+      expect(interaction.withRequestHeader).to.have.been.calledWith(
+        'Content-Type',
+        0,
+        'application/json'
+      );
+      expect(interaction.withRequestBody).to.have.been.calledWith(
+        JSON.stringify(body),
+        'application/json'
+      );
+    });
+
+    it('does not set a body for a complete request without one', () => {
+      unconfigured().withCompleteRequest({ method: 'GET', path: '/orders' });
+
+      expect(interaction.withRequest).to.have.been.calledWith('GET', '/orders');
+      expect(interaction.withRequestBody).not.to.have.been.called;
+    });
+
+    it('records the query and headers set by the request builder', () => {
+      unconfigured().withRequest('GET', '/orders', (builder) => {
+        builder.query({ page: '1' }).headers({ Accept: 'application/json' });
+      });
+
+      expect(recordedInteractions(pact)[0].request).to.deep.include({
+        query: { page: ['1'] },
+        headers: { Accept: ['application/json'] },
+      });
+    });
+
+    it('marks the interaction as pending', () => {
+      unconfigured().pending();
+
+      expect(interaction.setPending).to.have.been.calledWith(true);
+    });
+
+    it('adds each text comment', () => {
+      unconfigured().comment('first').comment('second');
+
+      expect(interaction.addTextComment).to.have.been.calledWith('first');
+      expect(interaction.addTextComment).to.have.been.calledWith('second');
+    });
+
+    it('sets all of the references together', () => {
+      unconfigured()
+        .reference('jira', 'ABC-1')
+        .reference('github', 'pact-js#1');
+
+      expect(interaction.setComment).to.have.been.calledWith(
+        'references',
+        JSON.stringify({ jira: 'ABC-1' })
+      );
+      expect(interaction.setComment).to.have.been.calledWith(
+        'references',
+        JSON.stringify({ jira: 'ABC-1', github: 'pact-js#1' })
+      );
+    });
+
+    it('sets the name of the test', () => {
+      unconfigured().testName('gets an order');
+
+      expect(interaction.setComment).to.have.been.calledWith(
+        'testname',
+        'gets an order'
+      );
+    });
+  });
+
+  describe('#addInteraction', () => {
+    let next: ConsumerInteraction;
+
+    beforeEach(() => {
+      next = {
+        uponReceiving: sinon.stub(),
+      } as unknown as ConsumerInteraction;
+      pact = {
+        newInteraction: sinon.stub().returns(next),
+      } as unknown as ConsumerPact;
+    });
+
+    const itAddsAnInteraction = (
+      addInteraction: () => V4UnconfiguredInteraction
+    ) => {
+      it('adds another interaction to the same pact', () => {
+        addInteraction().uponReceiving('a second request');
+
+        expect(pact.newInteraction).to.have.been.calledOnce;
+        expect(pact.newInteraction).to.have.been.calledWith('');
+        expect(next.uponReceiving).to.have.been.calledWith('a second request');
+        expect(recordedInteractions(pact)).to.deep.include({
+          description: 'a second request',
+          providerStates: [],
+        });
+      });
+    };
+
+    describe('after a response', () => {
+      itAddsAnInteraction(() =>
+        new InteractionWithResponse(pact, opts, sinon.stub()).addInteraction()
+      );
+    });
+
+    describe('after a plugin response', () => {
+      itAddsAnInteraction(() =>
+        new InteractionWithPluginResponse(
+          pact,
+          opts,
+          sinon.stub()
+        ).addInteraction()
+      );
+    });
+  });
+});
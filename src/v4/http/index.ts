@@ -2,8 +2,19 @@
 import { ConsumerInteraction, ConsumerPact } from '@pact-foundation/pact-core';
 import { JsonMap } from '../../common/jsonTypes';
 import { forEachObjIndexed } from 'ramda';
-import { Path, TemplateHeaders, TemplateQuery, V3MockServer } from '../../v3';
-import { Matcher, matcherValueOrString } from '../../v3/matchers';
+import {
+  CsvColumns,
+  CsvOptions,
+  csvTemplate,
+  Matcher,
+  Path,
+  TemplateFormBody,
+  TemplateHeaders,
+  TemplateQuery,
+  V3MockServer,
+  XmlBuilder,
+} from '../../v3';
+import { matcherValueOrString, validateTemplate } from '../../v3/matchers';
 import {
   PactV4Options,
   PluginConfig,
@@ -29,29 +40,58 @@ import {
 } from './types';
 import fs = require('fs');
 import {
+  contractMismatches,
   filterMissingFeatureFlag,
   generateMockServerError,
 } from '../../v3/display';
+import ContractMismatchError from '../../errors/contractMismatchError';
 import logger from '../../common/logger';
-
-type TemplateHeaderArrayValue = string[] | Matcher<string>[];
+import {
+  expectedRequests,
+  recordedRequest,
+  recordedValues,
+  reportConsumerTest,
+} from '../../reporters/consumer';
+import {
+  recordedInteractions,
+  recordInteraction,
+  recordRequestParts,
+} from '../reporting';
+import { addReference } from '../comments';
+import {
+  CONTENT_TYPE_FORM_URLENCODED,
+  setRequestBody,
+  setRequestDetails,
+  setRequestHeaders,
+  setResponseBody,
+  setResponseDetails,
+  setResponseHeaders,
+  validateFormBody,
+  validateJsonContentType,
+  validateTextBody,
+  validateXmlContentType,
+} from '../../v3/ffi';
 
 export class UnconfiguredInteraction implements V4UnconfiguredInteraction {
-  // tslint:disable:no-empty-function
   constructor(
     protected pact: ConsumerPact,
     protected interaction: ConsumerInteraction,
     protected opts: PactV4Options,
     protected cleanupFn: () => void
-  ) {}
+  ) {
+    recordInteraction(pact, interaction);
+  }
 
   uponReceiving(description: string): V4UnconfiguredInteraction {
     this.interaction.uponReceiving(description);
+    recordInteraction(this.pact, this.interaction).description = description;
 
     return this;
   }
 
   given(state: string, parameters?: JsonMap): V4UnconfiguredInteraction {
+    recordInteraction(this.pact, this.interaction).providerStates.push(state);
+
     if (parameters) {
       this.interaction.givenWithParams(state, JSON.stringify(parameters));
     } else {
@@ -61,7 +101,38 @@ export class UnconfiguredInteraction implements V4UnconfiguredInteraction {
     return this;
   }
 
+  pending(pending = true): V4UnconfiguredInteraction {
+    this.interaction.setPending(pending);
+
+    return this;
+  }
+
+  comment(text: string): V4UnconfiguredInteraction {
+    this.interaction.addTextComment(text);
+
+    return this;
+  }
+
+  reference(kind: string, value: string): V4UnconfiguredInteraction {
+    addReference(this.interaction, kind, value);
+
+    return this;
+  }
+
+  testName(name: string): V4UnconfiguredInteraction {
+    this.interaction.setComment('testname', name);
+
+    return this;
+  }
+
   withCompleteRequest(request: V4Request): V4InteractionWithCompleteRequest {
+    setRequestBody(this.interaction, request);
+    setRequestDetails(this.interaction, request);
+    recordInteraction(this.pact, this.interaction).request = recordedRequest(
+      request,
+      request.body
+    );
+
     return new InteractionWithCompleteRequest(
       this.pact,
       this.interaction,
@@ -76,6 +147,10 @@ export class UnconfiguredInteraction implements V4UnconfiguredInteraction {
     builder?: V4RequestBuilderFunc
   ): V4InteractionwithRequest {
     this.interaction.withRequest(method, matcherValueOrString(path));
+    recordInteraction(this.pact, this.interaction).request = recordedRequest({
+      method,
+      path,
+    });
 
     if (builder) {
       builder(new RequestBuilder(this.interaction));
@@ -103,16 +178,18 @@ export class UnconfiguredInteraction implements V4UnconfiguredInteraction {
 export class InteractionWithCompleteRequest
   implements V4InteractionWithCompleteRequest
 {
+  // tslint:disable:no-empty-function
   constructor(
     private pact: ConsumerPact,
     private interaction: ConsumerInteraction,
     private opts: PactV4Options,
     protected cleanupFn: () => void
-  ) {
-    throw Error('V4InteractionWithCompleteRequest is unimplemented');
-  }
+  ) {}
 
   withCompleteResponse(response: V4Response): V4InteractionWithResponse {
+    setResponseDetails(this.interaction, response);
+    setResponseBody(this.interaction, response);
+
     return new InteractionWithResponse(this.pact, this.opts, this.cleanupFn);
   }
 }
@@ -151,35 +228,56 @@ export class RequestBuilder implements V4RequestBuilder {
         this.interaction.withQuery(k, 0, matcherValueOrString(v));
       }
     }, query);
+    recordRequestParts(this.interaction, { query: recordedValues(query) });
 
     return this;
   }
 
   headers(headers: TemplateHeaders) {
-    forEachObjIndexed((v, k) => {
-      if (Array.isArray(v)) {
-        (v as TemplateHeaderArrayValue).forEach(
-          (header: string | Matcher<string>, index: number) => {
-            this.interaction.withRequestHeader(
-              `${k}`,
-              index,
-              matcherValueOrString(header)
-            );
-          }
-        );
-      } else {
-        this.interaction.withRequestHeader(`${k}`, 0, matcherValueOrString(v));
-      }
-    }, headers);
+    setRequestHeaders(this.interaction, headers);
+    recordRequestParts(this.interaction, {
+      headers: recordedValues(headers),
+    });
+
+    return this;
+  }
+
+  jsonBody(body: unknown, contentType = 'application/json') {
+    validateJsonContentType(contentType);
+    validateTemplate(body);
+    this.interaction.withRequestBody(matcherValueOrString(body), contentType);
+    recordRequestParts(this.interaction, { body });
+    return this;
+  }
+
+  formBody(body: TemplateFormBody) {
+    validateFormBody(body);
+    this.interaction.withRequestBody(
+      JSON.stringify(body),
+      CONTENT_TYPE_FORM_URLENCODED
+    );
 
     return this;
   }
 
-  jsonBody(body: unknown) {
+  textBody(body: string | Matcher<string>, contentType = 'text/plain') {
+    validateTextBody(body);
+    this.interaction.withRequestBody(matcherValueOrString(body), contentType);
+
+    return this;
+  }
+
+  csvBody(columns: CsvColumns, options?: CsvOptions) {
+    return this.textBody(csvTemplate(columns, options), 'text/csv');
+  }
+
+  xmlBody(body: XmlBuilder | string, contentType = 'application/xml') {
+    validateXmlContentType(contentType);
     this.interaction.withRequestBody(
-      matcherValueOrString(body),
-      'application/json'
+      typeof body === 'string' ? body : JSON.stringify(body),
+      contentType
     );
+
     return this;
   }
 
@@ -211,18 +309,46 @@ export class ResponseBuilder implements V4ResponseBuilder {
   }
 
   headers(headers: TemplateHeaders) {
-    forEachObjIndexed((v, k) => {
-      this.interaction.withResponseHeader(`${k}`, 0, matcherValueOrString(v));
-    }, headers);
+    setResponseHeaders(this.interaction, headers);
+
+    return this;
+  }
+
+  jsonBody(body: unknown, contentType = 'application/json') {
+    validateJsonContentType(contentType);
+    validateTemplate(body);
+    this.interaction.withResponseBody(matcherValueOrString(body), contentType);
+    return this;
+  }
+
+  formBody(body: TemplateFormBody) {
+    validateFormBody(body);
+    this.interaction.withResponseBody(
+      JSON.stringify(body),
+      CONTENT_TYPE_FORM_URLENCODED
+    );
 
     return this;
   }
 
-  jsonBody(body: unknown) {
+  textBody(body: string | Matcher<string>, contentType = 'text/plain') {
+    validateTextBody(body);
+    this.interaction.withResponseBody(matcherValueOrString(body), contentType);
+
+    return this;
+  }
+
+  csvBody(columns: CsvColumns, options?: CsvOptions) {
+    return this.textBody(csvTemplate(columns, options), 'text/csv');
+  }
+
+  xmlBody(body: XmlBuilder | string, contentType = 'application/xml') {
+    validateXmlContentType(contentType);
     this.interaction.withResponseBody(
-      matcherValueOrString(body),
-      'application/json'
+      typeof body === 'string' ? body : JSON.stringify(body),
+      contentType
     );
+
     return this;
   }
 
@@ -254,6 +380,15 @@ export class InteractionWithResponse implements V4InteractionWithResponse {
     protected cleanupFn: () => void
   ) {}
 
+  addInteraction(): V4UnconfiguredInteraction {
+    return new UnconfiguredInteraction(
+      this.pact,
+      this.pact.newInteraction(''),
+      this.opts,
+      this.cleanupFn
+    );
+  }
+
   async executeTest<T>(testFn: TestFunction<T>) {
     return executeTest(this.pact, this.opts, testFn, this.cleanupFn);
   }
@@ -281,6 +416,10 @@ export class InteractionWithPlugin implements V4InteractionWithPlugin {
     builder?: V4PluginRequestBuilderFunc
   ): V4InteractionWithPluginRequest {
     this.interaction.withRequest(method, matcherValueOrString(path));
+    recordInteraction(this.pact, this.interaction).request = recordedRequest({
+      method,
+      path,
+    });
 
     if (typeof builder === 'function') {
       builder(new RequestWithPluginBuilder(this.interaction));
@@ -363,6 +502,15 @@ export class InteractionWithPluginResponse
     protected cleanupFn: () => void
   ) {}
 
+  addInteraction(): V4UnconfiguredInteraction {
+    return new UnconfiguredInteraction(
+      this.pact,
+      this.pact.newInteraction(''),
+      this.opts,
+      this.cleanupFn
+    );
+  }
+
   async executeTest<T>(testFn: (mockServer: V4MockServer) => Promise<T>) {
     return executeTest(this.pact, this.opts, testFn, this.cleanupFn);
   }
@@ -403,6 +551,7 @@ const executeTest = async <T>(
   const port = pact.createMockServer(host, opts.port || 0, false);
 
   const server = { port, url: `${scheme}://${host}:${port}`, id: 'unknown' };
+  const started = new Date();
   let val: T | undefined;
   let error: Error | undefined;
 
@@ -415,25 +564,39 @@ const executeTest = async <T>(
   const matchingResults = pact.mockServerMismatches(port);
   const errors = filterMissingFeatureFlag(matchingResults);
   const success = pact.mockServerMatchedSuccessfully(port);
+  const failed = !success && errors.length > 0;
+  const interactions = recordedInteractions(pact);
+  const expected = expectedRequests(interactions);
+
+  const mismatches = failed
+    ? contractMismatches(matchingResults, opts.diff, expected)
+    : [];
+
+  await reportConsumerTest(opts, interactions, started, mismatches, error);
 
   // Scenario: Pact validation failed
-  if (!success && errors.length > 0) {
+  if (failed) {
     let errorMessage = 'Test failed for the following reasons:';
-    errorMessage += `\n\n  ${generateMockServerError(matchingResults, '\t')}`;
+    errorMessage += `\n\n  ${generateMockServerError(
+      matchingResults,
+      '\t',
+      opts.diff,
+      expected
+    )}`;
 
     cleanup(false, pact, opts, server, cleanupFn);
 
-    // If the tests throws an error, we need to rethrow the error, but print out
-    // any additional mock server errors to help the user understand what happened
-    // (The proximate cause here is often the HTTP 500 from the mock server,
-    // where the HTTP client then throws)
+    // Print out the mock server errors to help the user understand what
+    // happened. If the test threw an error, we need to rethrow it (the
+    // proximate cause here is often the HTTP 500 from the mock server, where
+    // the HTTP client then throws)
+    logger.error(errorMessage);
     if (error) {
-      logger.error(errorMessage);
       throw error;
     }
 
     // Test didn't throw, so we need to ensure the test fails
-    return Promise.reject(new Error(errorMessage));
+    return Promise.reject(new ContractMismatchError(errorMessage, mismatches));
   }
 
   // Scenario: test threw an error, but Pact validation was OK (error in client or test)
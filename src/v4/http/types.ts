@@ -1,14 +1,21 @@
 import { JsonMap } from '../../common/jsonTypes';
 import {
+  CsvColumns,
+  CsvOptions,
+  DiffOptions,
+  Matcher,
   Path,
   SpecificationVersion,
+  TemplateFormBody,
   TemplateHeaders,
   TemplateQuery,
   V3MockServer,
   V3ProviderState,
   V3Request,
   V3Response,
+  XmlBuilder,
 } from '../../v3';
+import { Reporter } from '../../reporters';
 
 // TODO: do we alias all types to V4 or is this yicky??
 //       These types are all interface types, so any extensions/modifications
@@ -51,9 +58,40 @@ export interface PactV4Options {
    * The host to run the mock service, defaults to 127.0.0.1
    */
   host?: string;
+  /**
+   * How body differences are shown when the mock server reports a mismatch
+   */
+  diff?: DiffOptions;
+  /**
+   * Reporters to send the result of each interaction to, e.g. `junitReporter`
+   */
+  reporters?: Reporter[];
 }
 
-export interface V4UnconfiguredInteraction {
+export interface V4InteractionMetadata<T> {
+  /**
+   * Marks the interaction as pending. Failures verifying a pending interaction
+   * will not fail the provider build
+   */
+  pending(pending?: boolean): T;
+  /**
+   * Adds a free text comment to the interaction
+   */
+  comment(text: string): T;
+  /**
+   * Adds a reference to an external resource (e.g. an issue tracker)
+   * @param kind Kind of reference, e.g. `jira`
+   * @param value The reference itself, e.g. `ABC-123`
+   */
+  reference(kind: string, value: string): T;
+  /**
+   * Sets the name of the test that generated the interaction
+   */
+  testName(name: string): T;
+}
+
+export interface V4UnconfiguredInteraction
+  extends V4InteractionMetadata<V4UnconfiguredInteraction> {
   given(state: string, parameters?: JsonMap): V4UnconfiguredInteraction;
   uponReceiving(description: string): V4UnconfiguredInteraction;
   withCompleteRequest(request: V4Request): V4InteractionWithCompleteRequest;
@@ -83,7 +121,33 @@ export type V4RequestBuilderFunc = (builder: V4RequestBuilder) => void;
 export interface V4RequestBuilder {
   query(query: TemplateQuery): V4RequestBuilder;
   headers(headers: TemplateHeaders): V4RequestBuilder;
-  jsonBody(body: unknown): V4RequestBuilder;
+  /**
+   * Sets a JSON body (defaults to application/json). Any media type with the
+   * +json suffix, such as application/hal+json, may be used instead
+   */
+  jsonBody(body: unknown, contentType?: string): V4RequestBuilder;
+  /**
+   * Sets an application/x-www-form-urlencoded body. Fields may use matchers
+   */
+  formBody(body: TemplateFormBody): V4RequestBuilder;
+  /**
+   * Sets a text body (defaults to text/plain). The body may be a matcher
+   * with a string example, such as `regex` or `includes`, which is applied to
+   * the whole body
+   */
+  textBody(
+    body: string | Matcher<string>,
+    contentType?: string
+  ): V4RequestBuilder;
+  /**
+   * Sets a text/csv body, where each column may have its own matcher
+   */
+  csvBody(columns: CsvColumns, options?: CsvOptions): V4RequestBuilder;
+  /**
+   * Sets an XML body (defaults to application/xml) from an {@link XmlBuilder},
+   * or the string returned from `XmlBuilder.build`
+   */
+  xmlBody(body: XmlBuilder | string, contentType?: string): V4RequestBuilder;
   binaryFile(contentType: string, file: string): V4RequestBuilder;
   multipartBody(
     contentType: string,
@@ -95,7 +159,18 @@ export interface V4RequestBuilder {
 
 export interface V4ResponseBuilder {
   headers(headers: TemplateHeaders): V4ResponseBuilder;
-  jsonBody(body: unknown): V4ResponseBuilder;
+  /**
+   * Sets a JSON body (defaults to application/json). Any media type with the
+   * +json suffix, such as application/hal+json, may be used instead
+   */
+  jsonBody(body: unknown, contentType?: string): V4ResponseBuilder;
+  formBody(body: TemplateFormBody): V4ResponseBuilder;
+  textBody(
+    body: string | Matcher<string>,
+    contentType?: string
+  ): V4ResponseBuilder;
+  csvBody(columns: CsvColumns, options?: CsvOptions): V4ResponseBuilder;
+  xmlBody(body: XmlBuilder | string, contentType?: string): V4ResponseBuilder;
   binaryFile(contentType: string, file: string): V4ResponseBuilder;
   multipartBody(
     contentType: string,
@@ -108,6 +183,11 @@ export interface V4ResponseBuilder {
 export type V4ResponseBuilderFunc = (builder: V4ResponseBuilder) => void;
 
 export interface V4InteractionWithResponse {
+  /**
+   * Registers another HTTP interaction on the same pact, so that all of them
+   * are served by a single mock server when `executeTest` is called
+   */
+  addInteraction(): V4UnconfiguredInteraction;
   executeTest<T>(
     testFn: (mockServer: V4MockServer) => Promise<T>
   ): Promise<T | undefined>;
@@ -145,7 +225,24 @@ export interface V4InteractionWithPluginRequest {
 export interface V4RequestWithPluginBuilder {
   query(query: TemplateQuery): V4RequestWithPluginBuilder;
   headers(headers: TemplateHeaders): V4RequestWithPluginBuilder;
-  jsonBody(body: unknown): V4RequestWithPluginBuilder;
+  /**
+   * Sets a JSON body (defaults to application/json). Any media type with the
+   * +json suffix, such as application/hal+json, may be used instead
+   */
+  jsonBody(body: unknown, contentType?: string): V4RequestWithPluginBuilder;
+  formBody(body: TemplateFormBody): V4RequestWithPluginBuilder;
+  textBody(
+    body: string | Matcher<string>,
+    contentType?: string
+  ): V4RequestWithPluginBuilder;
+  csvBody(
+    columns: CsvColumns,
+    options?: CsvOptions
+  ): V4RequestWithPluginBuilder;
+  xmlBody(
+    body: XmlBuilder | string,
+    contentType?: string
+  ): V4RequestWithPluginBuilder;
   binaryFile(contentType: string, file: string): V4RequestWithPluginBuilder;
   multipartBody(
     contentType: string,
@@ -161,7 +258,14 @@ export interface V4RequestWithPluginBuilder {
 
 export interface V4ResponseWithPluginBuilder {
   headers(headers: TemplateHeaders): V4ResponseBuilder;
-  jsonBody(body: unknown): V4ResponseBuilder;
+  jsonBody(body: unknown, contentType?: string): V4ResponseBuilder;
+  formBody(body: TemplateFormBody): V4ResponseBuilder;
+  textBody(
+    body: string | Matcher<string>,
+    contentType?: string
+  ): V4ResponseBuilder;
+  csvBody(columns: CsvColumns, options?: CsvOptions): V4ResponseBuilder;
+  xmlBody(body: XmlBuilder | string, contentType?: string): V4ResponseBuilder;
   binaryFile(contentType: string, file: string): V4ResponseBuilder;
   multipartBody(
     contentType: string,
@@ -173,6 +277,11 @@ export interface V4ResponseWithPluginBuilder {
 }
 
 export interface V4InteractionWithPluginResponse {
+  /**
+   * Registers another HTTP interaction on the same pact, so that all of them
+   * are served by a single mock server when `executeTest` is called
+   */
+  addInteraction(): V4UnconfiguredInteraction;
   executeTest<T>(
     testFn: (mockServer: V4MockServer) => Promise<T>
   ): Promise<T | undefined>;
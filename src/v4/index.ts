@@ -3,13 +3,29 @@ import { UnconfiguredInteraction } from './http';
 import { PactV4Options, V4UnconfiguredInteraction } from './http/types';
 import { V4ConsumerPact } from './types';
 import { version as pactPackageVersion } from '../../package.json';
-import { V4UnconfiguredSynchronousMessage } from './message/types';
-import { UnconfiguredSynchronousMessage } from './message';
+import {
+  V4UnconfiguredAsynchronousMessage,
+  V4UnconfiguredSynchronousMessage,
+} from './message/types';
+import {
+  UnconfiguredAsynchronousMessage,
+  UnconfiguredSynchronousMessage,
+} from './message';
 import { SpecificationVersion } from '../v3';
+import { recordInteraction } from './reporting';
+
+export { addInteractionFromOpenApi } from './openapi';
 
 export class PactV4 implements V4ConsumerPact {
   private pact: ConsumerPact;
 
+  // This function needs to be called if the PactV4 object is to be re-used (commonly expected by users)
+  // Because of the type-state model used here, it's a bit awkward as we need to thread this through
+  // to children, ultimately to be called on the "executeTest" stage.
+  private cleanupFn = (): void => {
+    this.setup();
+  };
+
   constructor(private opts: PactV4Options) {
     this.setup();
     this.pact.addMetadata('pact-js', 'version', pactPackageVersion);
@@ -29,28 +45,35 @@ export class PactV4 implements V4ConsumerPact {
       this.pact,
       this.pact.newInteraction(''),
       this.opts,
-      () => {
-        // This function needs to be called if the PactV4 object is to be re-used (commonly expected by users)
-        // Because of the type-state model used here, it's a bit awkward as we need to thread this through
-        // to children, ultimately to be called on the "executeTest" stage.
-        this.setup();
-      }
+      this.cleanupFn
     );
   }
 
   addSynchronousInteraction(
     description: string
   ): V4UnconfiguredSynchronousMessage {
+    const message = this.pact.newSynchronousMessage(description);
+    recordInteraction(this.pact, message).description = description;
+
     return new UnconfiguredSynchronousMessage(
       this.pact,
-      this.pact.newSynchronousMessage(description),
+      message,
+      this.opts,
+      this.cleanupFn
+    );
+  }
+
+  addAsynchronousMessage(
+    description: string
+  ): V4UnconfiguredAsynchronousMessage {
+    const message = this.pact.newAsynchronousMessage(description);
+    recordInteraction(this.pact, message).description = description;
+
+    return new UnconfiguredAsynchronousMessage(
+      this.pact,
+      message,
       this.opts,
-      () => {
-        // This function needs to be called if the PactV4 object is to be re-used (commonly expected by users)
-        // Because of the type-state model used here, it's a bit awkward as we need to thread this through
-        // to children, ultimately to be called on the "executeTest" stage.
-        this.setup();
-      }
+      this.cleanupFn
     );
   }
 }
@@ -0,0 +1,331 @@
+import {
+  AsynchronousMessage,
+  ConsumerPact,
+  SynchronousMessage,
+} from '@pact-foundation/pact-core';
+import chai from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import sinon from 'sinon';
+import sinonChai from 'sinon-chai';
+import {
+  UnconfiguredAsynchronousMessage,
+  UnconfiguredSynchronousMessage,
+} from '.';
+import { like, regex } from '../../v3/matchers';
+
+chai.use(sinonChai);
+chai.use(chaiAsPromised);
+
+const { expect } = chai;
+
+const opts = { consumer: 'consumer', provider: 'provider', dir: '/tmp' };
+
+const stubPact = () =>
+  ({
+    addPlugin: sinon.stub(),
+    writePactFile: sinon.stub(),
+    cleanupPlugins: sinon.stub(),
+  } as unknown as ConsumerPact);
+
+describe('V4 synchronous messages', () => {
+  let pact: ConsumerPact;
+  let message: SynchronousMessage;
+
+  beforeEach(() => {
+    pact = stubPact();
+    message = {
+      withRequestContents: sinon.stub(),
+      withResponseContents: sinon.stub(),
+      withMetadata: sinon.stub(),
+      withPluginRequestResponseInteractionContents: sinon.stub(),
+      setPending: sinon.stub(),
+      addTextComment: sinon.stub(),
+      setComment: sinon.stub(),
+    } as unknown as SynchronousMessage;
+  });
+
+  const unconfigured = () =>
+    new UnconfiguredSynchronousMessage(pact, message, opts, sinon.stub());
+
+  it('sets the pending flag, comments and test name', () => {
+    unconfigured()
+      .pending()
+      .comment('a comment')
+      .reference('jira', 'ABC-1')
+      .testName('gets a dog');
+
+    expect(message.setPending).to.have.been.calledWith(true);
+    expect(message.addTextComment).to.have.been.calledWith('a comment');
+    expect(message.setComment).to.have.been.calledWith(
+      'references',
+      '{"jira":"ABC-1"}'
+    );
+    expect(message.setComment).to.have.been.calledWith(
+      'testname',
+      'gets a dog'
+    );
+  });
+
+  it('gives the test the reified request and response', async () => {
+    const test = sinon.stub().resolves();
+
+    await unconfigured()
+      .withRequest((builder) => {
+        builder.withJSONContent({ id: like(1) });
+      })
+      .withResponse((builder) => {
+        builder.withJSONContent({ id: like(1), name: like('rover') });
+        builder.withMetadata({ 'content-type': 'application/json' });
+      })
+      .executeTest(test);
+
+    expect(test).to.have.been.calledWith({
+      Request: {
+        contents: Buffer.from('{"id":1}'),
+        contentType: 'application/json',
+        json: { id: 1 },
+      },
+      Response: [
+        {
+          contents: Buffer.from('{"id":1,"name":"rover"}'),
+          contentType: 'application/json',
+          json: { id: 1, name: 'rover' },
+          metadata: { 'content-type': 'application/json' },
+        },
+      ],
+    });
+    expect(pact.writePactFile).to.have.been.calledWith('/tmp');
+  });
+
+  it('gives text contents as the examples of their matchers', async () => {
+    const test = sinon.stub().resolves();
+
+    await unconfigured()
+      .withRequest((builder) => {
+        builder.withTextContent(regex('\\d+', '42'));
+      })
+      .withResponse((builder) => {
+        builder.withTextContent('ok');
+      })
+      .executeTest(test);
+
+    const [m] = test.firstCall.args;
+    expect(m.Request.contents).to.deep.eq(Buffer.from('42'));
+    expect(m.Response[0].contents).to.deep.eq(Buffer.from('ok'));
+  });
+
+  it('gives the test the message reified by the core, where it can', async () => {
+    (message as unknown as { reifyMessage: sinon.SinonStub }).reifyMessage =
+      sinon.stub().returns(
+        JSON.stringify({
+          request: { contents: { id: 7 } },
+          response: [{ contents: { id: 7, name: 'rex' } }],
+        })
+      );
+    const test = sinon.stub().resolves();
+
+    await unconfigured()
+      .withRequest((builder) => {
+        builder.withJSONContent({ id: like(1) });
+      })
+      .withResponse((builder) => {
+        builder.withJSONContent({ id: like(1), name: like('rover') });
+      })
+      .executeTest(test);
+
+    const [m] = test.firstCall.args;
+    expect(m.Request.json).to.deep.eq({ id: 7 });
+    expect(m.Request.contents).to.deep.eq(Buffer.from('{"id":7}'));
+    expect(m.Response[0].json).to.deep.eq({ id: 7, name: 'rex' });
+  });
+
+  it('gives the test a message without contents for plugin contents', async () => {
+    const test = sinon.stub().resolves();
+
+    await unconfigured()
+      .usingPlugin({ plugin: 'matt', version: '0.1.1' })
+      .withPluginContents('{"request":{"body":"hello"}}', 'application/matt')
+      .executeTest(test);
+
+    expect(test).to.have.been.calledOnce;
+    expect(test).to.have.been.calledWith({
+      Request: { contents: Buffer.alloc(0), contentType: '' },
+      Response: [],
+    });
+  });
+});
+
+describe('V4 asynchronous messages', () => {
+  let pact: ConsumerPact;
+  let message: AsynchronousMessage;
+  let cleanupFn: sinon.SinonStub;
+
+  beforeEach(() => {
+    pact = stubPact();
+    message = {
+      given: sinon.stub(),
+      givenWithParams: sinon.stub(),
+      withContents: sinon.stub(),
+      withBinaryContents: sinon.stub(),
+      withMetadata: sinon.stub(),
+      withPluginRequestInteractionContents: sinon.stub(),
+      reifyMessage: sinon
+        .stub()
+        .returns(
+          JSON.stringify({ contents: { id: 1 }, metadata: { queue: 'q' } })
+        ),
+      setPending: sinon.stub(),
+      addTextComment: sinon.stub(),
+      setComment: sinon.stub(),
+    } as unknown as AsynchronousMessage;
+    cleanupFn = sinon.stub();
+  });
+
+  const unconfigured = () =>
+    new UnconfiguredAsynchronousMessage(pact, message, opts, cleanupFn);
+
+  describe('UnconfiguredAsynchronousMessage', () => {
+    it('sets the provider states', () => {
+      unconfigured()
+        .given('an order exists')
+        .given('a user exists', { id: 1, name: 'Fred' });
+
+      expect(message.given).to.have.been.calledWith('an order exists');
+      expect(message.givenWithParams).to.have.been.calledWith(
+        'a user exists',
+        '{"id":1,"name":"Fred"}'
+      );
+    });
+
+    it('sets the pending flag, comments and test name', () => {
+      unconfigured()
+        .pending(false)
+        .comment('a comment')
+        .reference('jira', 'ABC-1')
+        .testName('handles an order');
+
+      expect(message.setPending).to.have.been.calledWith(false);
+      expect(message.addTextComment).to.have.been.calledWith('a comment');
+      expect(message.setComment).to.have.been.calledWith(
+        'references',
+        '{"jira":"ABC-1"}'
+      );
+      expect(message.setComment).to.have.been.calledWith(
+        'testname',
+        'handles an order'
+      );
+    });
+
+    it('sets the JSON contents, with their matchers', () => {
+      unconfigured().withJSONContent({ id: like(1) });
+
+      expect(message.withContents).to.have.been.calledWith(
+        JSON.stringify({ id: like(1) }),
+        'application/json'
+      );
+    });
+
+    it('rejects empty JSON contents', () => {
+      expect(() => unconfigured().withJSONContent({})).to.throw(
+        'You must provide a valid JSON document'
+      );
+    });
+
+    it('sets binary contents', () => {
+      const body = Buffer.from('data');
+
+      unconfigured().withContent('application/octet-stream', body);
+
+      expect(message.withBinaryContents).to.have.been.calledWith(
+        body,
+        'application/octet-stream'
+      );
+    });
+
+    it('sets the contents of a plugin on the message', () => {
+      const contents = '{"pact:proto":"order.proto"}';
+
+      unconfigured()
+        .usingPlugin({ plugin: 'protobuf', version: '0.3.0' })
+        .withPluginContents(contents, 'application/protobuf');
+
+      expect(pact.addPlugin).to.have.been.calledWith('protobuf', '0.3.0');
+      expect(
+        message.withPluginRequestInteractionContents
+      ).to.have.been.calledWith('application/protobuf', contents);
+    });
+
+    it('rejects plugin contents when the core can not set them', () => {
+      delete (
+        message as unknown as { withPluginRequestInteractionContents?: unknown }
+      ).withPluginRequestInteractionContents;
+
+      expect(() =>
+        unconfigured()
+          .usingPlugin({ plugin: 'protobuf', version: '0.3.0' })
+          .withPluginContents('{}', 'application/protobuf')
+      ).to.throw(/does not support plugin contents/);
+    });
+  });
+
+  describe('AsynchronousMessageWithContents', () => {
+    it('sets each metadata value as JSON', () => {
+      unconfigured()
+        .withJSONContent({ id: 1 })
+        .withMetadata({ queue: 'orders', priority: like('high') });
+
+      expect(message.withMetadata).to.have.been.calledWith('queue', '"orders"');
+      expect(message.withMetadata).to.have.been.calledWith(
+        'priority',
+        JSON.stringify(like('high'))
+      );
+    });
+
+    it('rejects empty metadata', () => {
+      expect(() =>
+        unconfigured().withJSONContent({ id: 1 }).withMetadata({})
+      ).to.throw('You must provide valid metadata');
+    });
+
+    it('gives the handler the reified message and writes the pact', async () => {
+      const handler = sinon.stub().resolves('done');
+
+      const result = await unconfigured()
+        .withJSONContent({ id: like(1) })
+        .executeTest(handler);
+
+      expect(result).to.eq('done');
+      expect(handler).to.have.been.calledWith({
+        contents: { id: 1 },
+        metadata: { queue: 'q' },
+      });
+      expect(pact.writePactFile).to.have.been.calledWith('/tmp');
+      expect(cleanupFn).to.have.been.called;
+    });
+
+    it('decodes binary contents for the handler', async () => {
+      (message.reifyMessage as sinon.SinonStub).returns(
+        JSON.stringify({ contents: Buffer.from('data').toString('base64') })
+      );
+      const handler = sinon.stub().resolves();
+
+      await unconfigured()
+        .withContent('application/octet-stream', Buffer.from('data'))
+        .executeTest(handler);
+
+      expect(handler.firstCall.args[0].contents).to.deep.eq(
+        Buffer.from('data')
+      );
+    });
+
+    it('does not write the pact when the handler fails', async () => {
+      const handler = sinon.stub().rejects(new Error('bad message'));
+
+      await expect(
+        unconfigured().withJSONContent({ id: 1 }).executeTest(handler)
+      ).to.be.rejectedWith('bad message');
+      expect(pact.writePactFile).not.to.have.been.called;
+      expect(cleanupFn).to.have.been.called;
+    });
+  });
+});
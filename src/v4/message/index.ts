@@ -1,10 +1,13 @@
 /* eslint-disable */
-import { Metadata } from '../../dsl/message';
+import { ConcreteMessage, Metadata } from '../../dsl/message';
 import { AnyJson, JsonMap } from '../../common/jsonTypes';
 import {
+  MessageContents,
   PluginConfig,
   SynchronousMessage,
   TransportConfig,
+  V4AsynchronousMessageWithContents,
+  V4AsynchronousMessageWithPlugin,
   V4MessagePluginRequestBuilderFunc,
   V4MessagePluginResponseBuilderFunc,
   V4SynchronousMessageWithPlugin,
@@ -14,9 +17,11 @@ import {
   V4SynchronousMessageWithResponse,
   V4SynchronousMessageWithResponseBuilder,
   V4SynchronousMessageWithTransport,
+  V4UnconfiguredAsynchronousMessage,
   V4UnconfiguredSynchronousMessage,
 } from './types';
 import {
+  AsynchronousMessage as PactCoreAsynchronousMessage,
   SynchronousMessage as PactCoreSynchronousMessage,
   ConsumerPact,
 } from '@pact-foundation/pact-core';
@@ -24,14 +29,109 @@ import { PactV4Options } from '../http/types';
 import { forEachObjIndexed, isEmpty } from 'ramda';
 import ConfigurationError from '../../errors/configurationError';
 import {
+  contractMismatches,
   filterMissingFeatureFlag,
   generateMockServerError,
 } from '../../v3/display';
+import ContractMismatchError from '../../errors/contractMismatchError';
 import logger from '../../common/logger';
-import { isMatcher as isV3Matcher } from '../../v3/matchers';
+import {
+  isMatcher as isV3Matcher,
+  matcherValueOrString,
+  reify,
+} from '../../v3/matchers';
+import { CsvColumns, CsvOptions, csvTemplate, Matcher } from '../../v3';
+import { validateTextBody } from '../../v3/ffi';
+import { reportConsumerTest } from '../../reporters/consumer';
+import { recordedInteractions, recordInteraction } from '../reporting';
+import { addReference } from '../comments';
 
 const defaultPactDir = './pacts';
 
+const emptyMessageContents = (): MessageContents => ({
+  contents: Buffer.alloc(0),
+  contentType: '',
+});
+
+const textMessageContents = (
+  body: string | Matcher<string>,
+  contentType: string
+): MessageContents => ({
+  contents: Buffer.from(`${reify(body)}`),
+  contentType,
+});
+
+const jsonMessageContents = (content: unknown): MessageContents => {
+  const json = reify(content);
+
+  return {
+    contents: Buffer.from(JSON.stringify(json)),
+    contentType: 'application/json',
+    json,
+  };
+};
+
+const emptySynchronousMessage = (): SynchronousMessage => ({
+  Request: emptyMessageContents(),
+  Response: [],
+});
+
+// The contents and metadata of one side of a synchronous message, as reified
+// by the core
+interface ReifiedContents {
+  contents?: AnyJson;
+  metadata?: Record<string, AnyJson>;
+}
+
+// Older versions of the core can only reify asynchronous messages
+type ReifiableSynchronousMessage = PactCoreSynchronousMessage & {
+  reifyMessage?: () => string;
+};
+
+// Replaces the JSON contents and metadata of the example with those reified by
+// the core, which have any generators applied
+const withReified = (
+  example: MessageContents,
+  reified?: ReifiedContents
+): MessageContents => {
+  const json =
+    example.json !== undefined && reified?.contents !== undefined
+      ? reified.contents
+      : undefined;
+
+  return {
+    ...example,
+    ...(json !== undefined
+      ? { contents: Buffer.from(JSON.stringify(json)), json }
+      : {}),
+    ...(example.metadata && reified?.metadata
+      ? { metadata: reified.metadata }
+      : {}),
+  };
+};
+
+const reifiedSynchronousMessage = (
+  interaction: ReifiableSynchronousMessage,
+  message: SynchronousMessage
+): SynchronousMessage => {
+  if (typeof interaction.reifyMessage !== 'function') {
+    logger.debug(
+      'The core can not reify synchronous messages, so the test is given the example values'
+    );
+    return message;
+  }
+
+  const raw = interaction.reifyMessage();
+  logger.debug(`reified message raw: ${raw}`);
+
+  const { request, response = [] } = JSON.parse(raw);
+
+  return {
+    Request: withReified(message.Request, request),
+    Response: message.Response.map((r, i) => withReified(r, response[i])),
+  };
+};
+
 export class UnconfiguredSynchronousMessage
   implements V4UnconfiguredSynchronousMessage
 {
@@ -40,9 +140,13 @@ export class UnconfiguredSynchronousMessage
     protected interaction: PactCoreSynchronousMessage,
     protected opts: PactV4Options,
     protected cleanupFn: () => void
-  ) {}
+  ) {
+    recordInteraction(pact, interaction);
+  }
 
   given(state: string, parameters?: JsonMap): V4UnconfiguredSynchronousMessage {
+    recordInteraction(this.pact, this.interaction).providerStates.push(state);
+
     if (parameters) {
       this.interaction.givenWithParams(state, JSON.stringify(parameters));
     } else {
@@ -52,6 +156,30 @@ export class UnconfiguredSynchronousMessage
     return this;
   }
 
+  pending(pending = true): V4UnconfiguredSynchronousMessage {
+    this.interaction.setPending(pending);
+
+    return this;
+  }
+
+  comment(text: string): V4UnconfiguredSynchronousMessage {
+    this.interaction.addTextComment(text);
+
+    return this;
+  }
+
+  reference(kind: string, value: string): V4UnconfiguredSynchronousMessage {
+    addReference(this.interaction, kind, value);
+
+    return this;
+  }
+
+  testName(name: string): V4UnconfiguredSynchronousMessage {
+    this.interaction.setComment('testname', name);
+
+    return this;
+  }
+
   usingPlugin(config: PluginConfig): V4SynchronousMessageWithPlugin {
     this.pact.addPlugin(config.plugin, config.version);
 
@@ -66,11 +194,23 @@ export class UnconfiguredSynchronousMessage
   withRequest(
     r: V4MessagePluginRequestBuilderFunc
   ): V4SynchronousMessageWithRequest {
+    const message = emptySynchronousMessage();
+
+    r(
+      new SynchronousMessageWithRequestBuilder(
+        this.pact,
+        this.interaction,
+        this.opts,
+        message.Request
+      )
+    );
+
     return new SynchronousMessageWithRequest(
       this.pact,
       this.interaction,
       this.opts,
-      this.cleanupFn
+      this.cleanupFn,
+      message
     );
   }
 }
@@ -115,7 +255,8 @@ export class SynchronousMessageWithRequestBuilder
   constructor(
     protected pact: ConsumerPact,
     protected interaction: PactCoreSynchronousMessage,
-    protected opts: PactV4Options
+    protected opts: PactV4Options,
+    protected request: MessageContents
   ) {}
 
   withContent(
@@ -123,10 +264,32 @@ export class SynchronousMessageWithRequestBuilder
     body: Buffer
   ): V4SynchronousMessageWithRequestBuilder {
     this.interaction.withRequestBinaryContents(body, contentType);
+    Object.assign(this.request, { contents: body, contentType });
 
     return this;
   }
 
+  withTextContent(
+    body: string | Matcher<string>,
+    contentType = 'text/plain'
+  ): V4SynchronousMessageWithRequestBuilder {
+    validateTextBody(body);
+    this.interaction.withRequestContents(
+      matcherValueOrString(body),
+      contentType
+    );
+    Object.assign(this.request, textMessageContents(body, contentType));
+
+    return this;
+  }
+
+  withCSVContent(
+    columns: CsvColumns,
+    options?: CsvOptions
+  ): V4SynchronousMessageWithRequestBuilder {
+    return this.withTextContent(csvTemplate(columns, options), 'text/csv');
+  }
+
   withJSONContent(content: unknown): V4SynchronousMessageWithRequestBuilder {
     if (isEmpty(content)) {
       throw new ConfigurationError(
@@ -137,6 +300,7 @@ export class SynchronousMessageWithRequestBuilder
       JSON.stringify(content),
       'application/json'
     );
+    Object.assign(this.request, jsonMessageContents(content));
 
     return this;
   }
@@ -149,17 +313,22 @@ export class SynchronousMessageWithRequest
     protected pact: ConsumerPact,
     protected interaction: PactCoreSynchronousMessage,
     protected opts: PactV4Options,
-    protected cleanupFn: () => void
+    protected cleanupFn: () => void,
+    protected message: SynchronousMessage
   ) {}
 
   withResponse(
     builder: V4MessagePluginResponseBuilderFunc
   ): V4SynchronousMessageWithResponse {
+    const response = emptyMessageContents();
+    this.message.Response.push(response);
+
     builder(
       new SynchronousMessageWithResponseBuilder(
         this.pact,
         this.interaction,
-        this.opts
+        this.opts,
+        response
       )
     );
 
@@ -167,7 +336,8 @@ export class SynchronousMessageWithRequest
       this.pact,
       this.interaction,
       this.opts,
-      this.cleanupFn
+      this.cleanupFn,
+      this.message
     );
   }
 }
@@ -178,7 +348,8 @@ export class SynchronousMessageWithResponseBuilder
   constructor(
     protected pact: ConsumerPact,
     protected interaction: PactCoreSynchronousMessage,
-    protected opts: PactV4Options
+    protected opts: PactV4Options,
+    protected response: MessageContents
   ) {}
 
   withMetadata(metadata: Metadata): V4SynchronousMessageWithResponseBuilder {
@@ -191,6 +362,10 @@ export class SynchronousMessageWithResponseBuilder
     forEachObjIndexed((v, k) => {
       this.interaction.withMetadata(`${k}`, JSON.stringify(v));
     }, metadata);
+    this.response.metadata = {
+      ...this.response.metadata,
+      ...(reify(metadata) as JsonMap),
+    };
 
     return this;
   }
@@ -200,10 +375,32 @@ export class SynchronousMessageWithResponseBuilder
     body: Buffer
   ): V4SynchronousMessageWithResponseBuilder {
     this.interaction.withResponseBinaryContents(body, contentType);
+    Object.assign(this.response, { contents: body, contentType });
+
+    return this;
+  }
+
+  withTextContent(
+    body: string | Matcher<string>,
+    contentType = 'text/plain'
+  ): V4SynchronousMessageWithResponseBuilder {
+    validateTextBody(body);
+    this.interaction.withResponseContents(
+      matcherValueOrString(body),
+      contentType
+    );
+    Object.assign(this.response, textMessageContents(body, contentType));
 
     return this;
   }
 
+  withCSVContent(
+    columns: CsvColumns,
+    options?: CsvOptions
+  ): V4SynchronousMessageWithResponseBuilder {
+    return this.withTextContent(csvTemplate(columns, options), 'text/csv');
+  }
+
   withJSONContent(content: unknown): V4SynchronousMessageWithResponseBuilder {
     if (isEmpty(content)) {
       throw new ConfigurationError(
@@ -214,6 +411,7 @@ export class SynchronousMessageWithResponseBuilder
       JSON.stringify(content),
       'application/json'
     );
+    Object.assign(this.response, jsonMessageContents(content));
 
     return this;
   }
@@ -232,11 +430,13 @@ export class SynchronousMessageWithPluginContents
   executeTest<T>(
     integrationTest: (m: SynchronousMessage) => Promise<T>
   ): Promise<T | undefined> {
+    // Plugin contents are opaque to us, so the message has no contents
     return executeNonTransportTest(
       this.pact,
       this.opts,
       integrationTest,
-      this.cleanupFn
+      this.cleanupFn,
+      emptySynchronousMessage()
     );
   }
 
@@ -279,13 +479,14 @@ export class SynchronousMessageWithTransport
   async executeTest<T>(
     integrationTest: (tc: TransportConfig, m: SynchronousMessage) => Promise<T>
   ): Promise<T | undefined> {
+    const started = new Date();
     let val: T | undefined;
     let error: Error | undefined;
 
     try {
       val = await integrationTest(
         { port: this.port, address: this.address },
-        {} as SynchronousMessage
+        emptySynchronousMessage()
       );
     } catch (e) {
       error = e;
@@ -294,25 +495,44 @@ export class SynchronousMessageWithTransport
     const matchingResults = this.pact.mockServerMismatches(this.port);
     const errors = filterMissingFeatureFlag(matchingResults);
     const success = this.pact.mockServerMatchedSuccessfully(this.port);
+    const failed = !success && errors.length > 0;
+
+    const mismatches = failed
+      ? contractMismatches(matchingResults, this.opts.diff)
+      : [];
+
+    await reportConsumerTest(
+      this.opts,
+      recordedInteractions(this.pact),
+      started,
+      mismatches,
+      error
+    );
 
     // Scenario: Pact validation failed
-    if (!success && errors.length > 0) {
+    if (failed) {
       let errorMessage = 'Test failed for the following reasons:';
-      errorMessage += `\n\n  ${generateMockServerError(matchingResults, '\t')}`;
+      errorMessage += `\n\n  ${generateMockServerError(
+        matchingResults,
+        '\t',
+        this.opts.diff
+      )}`;
 
       cleanup(false, this.pact, this.opts, this.cleanupFn, this.port, true);
 
-      // If the tests throws an error, we need to rethrow the error, but print out
-      // any additional mock server errors to help the user understand what happened
-      // (The proximate cause here is often the HTTP 500 from the mock server,
-      // where the HTTP client then throws)
+      // Print out the mock server errors to help the user understand what
+      // happened. If the test threw an error, we need to rethrow it (the
+      // proximate cause here is often the HTTP 500 from the mock server, where
+      // the HTTP client then throws)
+      logger.error(errorMessage);
       if (error) {
-        logger.error(errorMessage);
         throw error;
       }
 
       // Test didn't throw, so we need to ensure the test fails
-      return Promise.reject(new Error(errorMessage));
+      return Promise.reject(
+        new ContractMismatchError(errorMessage, mismatches)
+      );
     }
 
     // Scenario: test threw an error, but Pact validation was OK (error in client or test)
@@ -335,21 +555,263 @@ export class SynchronousMessageWithResponse
     protected pact: ConsumerPact,
     protected interaction: PactCoreSynchronousMessage,
     protected opts: PactV4Options,
-    protected cleanupFn: () => void
+    protected cleanupFn: () => void,
+    protected message: SynchronousMessage
   ) {}
 
   executeTest<T>(
     integrationTest: (m: SynchronousMessage) => Promise<T>
   ): Promise<T | undefined> {
-    const res = executeNonTransportTest(
+    return executeNonTransportTest(
       this.pact,
       this.opts,
       integrationTest,
+      this.cleanupFn,
+      reifiedSynchronousMessage(this.interaction, this.message)
+    );
+  }
+}
+
+export class UnconfiguredAsynchronousMessage
+  implements V4UnconfiguredAsynchronousMessage
+{
+  constructor(
+    protected pact: ConsumerPact,
+    protected message: PactCoreAsynchronousMessage,
+    protected opts: PactV4Options,
+    protected cleanupFn: () => void
+  ) {
+    recordInteraction(pact, message);
+  }
+
+  given(
+    state: string,
+    parameters?: JsonMap
+  ): V4UnconfiguredAsynchronousMessage {
+    recordInteraction(this.pact, this.message).providerStates.push(state);
+
+    if (parameters) {
+      this.message.givenWithParams(state, JSON.stringify(parameters));
+    } else {
+      this.message.given(state);
+    }
+
+    return this;
+  }
+
+  pending(pending = true): V4UnconfiguredAsynchronousMessage {
+    this.message.setPending(pending);
+
+    return this;
+  }
+
+  comment(text: string): V4UnconfiguredAsynchronousMessage {
+    this.message.addTextComment(text);
+
+    return this;
+  }
+
+  reference(kind: string, value: string): V4UnconfiguredAsynchronousMessage {
+    addReference(this.message, kind, value);
+
+    return this;
+  }
+
+  testName(name: string): V4UnconfiguredAsynchronousMessage {
+    this.message.setComment('testname', name);
+
+    return this;
+  }
+
+  usingPlugin(config: PluginConfig): V4AsynchronousMessageWithPlugin {
+    this.pact.addPlugin(config.plugin, config.version);
+
+    return new AsynchronousMessageWithPlugin(
+      this.pact,
+      this.message,
+      this.opts,
+      this.cleanupFn
+    );
+  }
+
+  withJSONContent(content: unknown): V4AsynchronousMessageWithContents {
+    if (isEmpty(content)) {
+      throw new ConfigurationError(
+        'You must provide a valid JSON document or primitive for the Message.'
+      );
+    }
+    this.message.withContents(JSON.stringify(content), 'application/json');
+
+    return new AsynchronousMessageWithContents(
+      this.pact,
+      this.message,
+      this.opts,
+      this.cleanupFn
+    );
+  }
+
+  withTextContent(
+    body: string | Matcher<string>,
+    contentType = 'text/plain'
+  ): V4AsynchronousMessageWithContents {
+    validateTextBody(body);
+    this.message.withContents(matcherValueOrString(body), contentType);
+
+    return new AsynchronousMessageWithContents(
+      this.pact,
+      this.message,
+      this.opts,
       this.cleanupFn
     );
-    this.cleanupFn();
+  }
 
-    return res;
+  withCSVContent(
+    columns: CsvColumns,
+    options?: CsvOptions
+  ): V4AsynchronousMessageWithContents {
+    return this.withTextContent(csvTemplate(columns, options), 'text/csv');
+  }
+
+  withContent(
+    contentType: string,
+    body: Buffer
+  ): V4AsynchronousMessageWithContents {
+    this.message.withBinaryContents(body, contentType);
+
+    return new AsynchronousMessageWithContents(
+      this.pact,
+      this.message,
+      this.opts,
+      this.cleanupFn,
+      true
+    );
+  }
+}
+
+// Only some versions of the core can set plugin contents on an asynchronous
+// message
+type PluginAsynchronousMessage = PactCoreAsynchronousMessage & {
+  withPluginRequestInteractionContents?: (
+    contentType: string,
+    contents: string
+  ) => void;
+};
+
+export class AsynchronousMessageWithPlugin
+  implements V4AsynchronousMessageWithPlugin
+{
+  constructor(
+    protected pact: ConsumerPact,
+    protected message: PactCoreAsynchronousMessage,
+    protected opts: PactV4Options,
+    protected cleanupFn: () => void
+  ) {}
+
+  usingPlugin(config: PluginConfig): V4AsynchronousMessageWithPlugin {
+    this.pact.addPlugin(config.plugin, config.version);
+
+    return this;
+  }
+
+  withPluginContents(
+    contents: string,
+    contentType: string
+  ): V4AsynchronousMessageWithContents {
+    // The contents of an asynchronous message are the request part of the
+    // interaction, which is the part the core's message handle sets here
+    const { withPluginRequestInteractionContents } = this
+      .message as PluginAsynchronousMessage;
+    if (typeof withPluginRequestInteractionContents !== 'function') {
+      throw new ConfigurationError(
+        'The version of the Pact core in use does not support plugin contents for asynchronous messages'
+      );
+    }
+    withPluginRequestInteractionContents.call(
+      this.message,
+      contentType,
+      contents
+    );
+
+    return new AsynchronousMessageWithContents(
+      this.pact,
+      this.message,
+      this.opts,
+      this.cleanupFn
+    );
+  }
+}
+
+export class AsynchronousMessageWithContents
+  implements V4AsynchronousMessageWithContents
+{
+  constructor(
+    protected pact: ConsumerPact,
+    protected message: PactCoreAsynchronousMessage,
+    protected opts: PactV4Options,
+    protected cleanupFn: () => void,
+    protected binary = false
+  ) {}
+
+  withMetadata(metadata: Metadata): V4AsynchronousMessageWithContents {
+    if (isEmpty(metadata)) {
+      throw new ConfigurationError(
+        'You must provide valid metadata for the Message, or none at all'
+      );
+    }
+
+    forEachObjIndexed((v, k) => {
+      this.message.withMetadata(`${k}`, JSON.stringify(v));
+    }, metadata);
+
+    return this;
+  }
+
+  async executeTest<T>(
+    handler: (m: ConcreteMessage) => Promise<T>
+  ): Promise<T | undefined> {
+    const started = new Date();
+    let val: T | undefined;
+
+    try {
+      val = await handler(this.reifiedContent());
+    } catch (e) {
+      // Scenario: handler threw an error, don't write the message to the pact
+      await reportConsumerTest(
+        this.opts,
+        recordedInteractions(this.pact),
+        started,
+        [],
+        e
+      );
+      cleanup(false, this.pact, this.opts, this.cleanupFn);
+      throw e;
+    }
+
+    // Scenario: handler accepted the message - return the callback value
+    await reportConsumerTest(
+      this.opts,
+      recordedInteractions(this.pact),
+      started,
+      []
+    );
+    cleanup(true, this.pact, this.opts, this.cleanupFn);
+
+    return val;
+  }
+
+  // Generates the concrete message (i.e. with all matchers and generators
+  // applied) that the handler under test would receive from the provider
+  private reifiedContent(): ConcreteMessage {
+    const raw = this.message.reifyMessage();
+    logger.debug(`reified message raw: ${raw}`);
+
+    const reified: ConcreteMessage = JSON.parse(raw);
+
+    if (this.binary) {
+      reified.contents = Buffer.from(reified.contents as string, 'base64');
+    }
+
+    return reified;
   }
 }
 
@@ -379,17 +841,27 @@ const executeNonTransportTest = async <T>(
   pact: ConsumerPact,
   opts: PactV4Options,
   integrationTest: (m: SynchronousMessage) => Promise<T>,
-  cleanupFn: () => void
+  cleanupFn: () => void,
+  message: SynchronousMessage
 ): Promise<T | undefined> => {
+  const started = new Date();
   let val: T | undefined;
   let error: Error | undefined;
 
   try {
-    val = await integrationTest({} as SynchronousMessage);
+    val = await integrationTest(message);
   } catch (e) {
     error = e;
   }
 
+  await reportConsumerTest(
+    opts,
+    recordedInteractions(pact),
+    started,
+    [],
+    error
+  );
+
   // Scenario: test threw an error, but Pact validation was OK (error in client or test)
   if (error) {
     cleanup(false, pact, opts, cleanupFn);
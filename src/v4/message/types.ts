@@ -1,11 +1,38 @@
 import { AnyJson, JsonMap } from '../../common/jsonTypes';
-import { Metadata } from '../../dsl/message';
-
-export type MessageContents = unknown; // TODO { contents: Buffer }
+import { ConcreteMessage, Metadata } from '../../dsl/message';
+import { V4InteractionMetadata } from '../http/types';
+import { CsvColumns, CsvOptions, Matcher } from '../../v3';
+
+/**
+ * The concrete (reified) contents of one side of a message, as it
+ * would be sent over the wire
+ */
+export interface MessageContents {
+  /**
+   * The raw message payload
+   */
+  contents: Buffer;
+  /**
+   * Content type of the payload
+   */
+  contentType: string;
+  /**
+   * The decoded payload, if the contents are JSON
+   */
+  json?: AnyJson;
+  /**
+   * Metadata attached to the message, with any matchers removed
+   */
+  metadata?: Record<string, AnyJson>;
+}
 
-// TODO: this is currently an empty object,
-//       it will eventually be populated with a Buffer
-//       for the request and response body
+/**
+ * The concrete request and response of a synchronous message. JSON contents
+ * and metadata are reified by the core, applying any generators (e.g.
+ * `uuid()`), where the core supports it, and are otherwise the example values
+ * of the templates. Plugin contents are opaque to Pact JS, so the tests of
+ * plugin interactions are given a message without contents.
+ */
 export interface SynchronousMessage {
   Request: MessageContents;
   Response: MessageContents[];
@@ -33,7 +60,8 @@ export interface V4SynchronousPact {
   addSynchronousMessage(description: string): V4UnconfiguredSynchronousMessage;
 }
 
-export interface V4UnconfiguredSynchronousMessage {
+export interface V4UnconfiguredSynchronousMessage
+  extends V4InteractionMetadata<V4UnconfiguredSynchronousMessage> {
   given(state: string, parameters?: JsonMap): V4UnconfiguredSynchronousMessage;
   usingPlugin(config: PluginConfig): V4SynchronousMessageWithPlugin;
   withRequest(
@@ -60,6 +88,14 @@ export interface V4SynchronousMessageWithRequestBuilder {
     contentType: string,
     body: Buffer
   ): V4SynchronousMessageWithRequestBuilder;
+  withTextContent(
+    body: string | Matcher<string>,
+    contentType?: string
+  ): V4SynchronousMessageWithRequestBuilder;
+  withCSVContent(
+    columns: CsvColumns,
+    options?: CsvOptions
+  ): V4SynchronousMessageWithRequestBuilder;
   withJSONContent(content: unknown): V4SynchronousMessageWithRequestBuilder;
 }
 
@@ -75,6 +111,14 @@ export interface V4SynchronousMessageWithResponseBuilder {
     contentType: string,
     body: Buffer
   ): V4SynchronousMessageWithResponseBuilder;
+  withTextContent(
+    body: string | Matcher<string>,
+    contentType?: string
+  ): V4SynchronousMessageWithResponseBuilder;
+  withCSVContent(
+    columns: CsvColumns,
+    options?: CsvOptions
+  ): V4SynchronousMessageWithResponseBuilder;
   withJSONContent(content: unknown): V4SynchronousMessageWithResponseBuilder;
 }
 
@@ -100,3 +144,40 @@ export interface V4SynchronousMessageWithResponse {
     integrationTest: (m: SynchronousMessage) => Promise<T>
   ): Promise<T | undefined>;
 }
+
+export interface V4UnconfiguredAsynchronousMessage
+  extends V4InteractionMetadata<V4UnconfiguredAsynchronousMessage> {
+  given(
+    state: string,
+    parameters?: JsonMap
+  ): V4UnconfiguredAsynchronousMessage;
+  usingPlugin(config: PluginConfig): V4AsynchronousMessageWithPlugin;
+  withJSONContent(content: unknown): V4AsynchronousMessageWithContents;
+  withTextContent(
+    body: string | Matcher<string>,
+    contentType?: string
+  ): V4AsynchronousMessageWithContents;
+  withCSVContent(
+    columns: CsvColumns,
+    options?: CsvOptions
+  ): V4AsynchronousMessageWithContents;
+  withContent(
+    contentType: string,
+    body: Buffer
+  ): V4AsynchronousMessageWithContents;
+}
+
+export interface V4AsynchronousMessageWithPlugin {
+  usingPlugin(config: PluginConfig): V4AsynchronousMessageWithPlugin;
+  withPluginContents(
+    contents: string,
+    contentType: string
+  ): V4AsynchronousMessageWithContents;
+}
+
+export interface V4AsynchronousMessageWithContents {
+  withMetadata(metadata: Metadata): V4AsynchronousMessageWithContents;
+  executeTest<T>(
+    handler: (m: ConcreteMessage) => Promise<T>
+  ): Promise<T | undefined>;
+}
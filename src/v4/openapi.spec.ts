@@ -0,0 +1,74 @@
+import chai from 'chai';
+import sinon from 'sinon';
+import sinonChai from 'sinon-chai';
+import { addInteractionFromOpenApi } from './openapi';
+import { OpenApiDocument } from '../v3/openapi';
+import { V4UnconfiguredInteraction } from './http/types';
+import { V4ConsumerPact } from './types';
+
+chai.use(sinonChai);
+
+const { expect } = chai;
+
+const document: OpenApiDocument = {
+  openapi: '3.0.3',
+  paths: {
+    '/dogs': {
+      get: {
+        operationId: 'listDogs',
+        summary: 'a request for the dogs',
+        responses: {
+          '200': {
+            content: {
+              'application/json': {
+                schema: { type: 'object', properties: {} },
+              },
+            },
+          },
+        },
+      },
+    },
+  },
+};
+
+describe('V4 OpenAPI interactions', () => {
+  describe('#addInteractionFromOpenApi', () => {
+    it('adds the generated interaction to the pact', () => {
+      const response = {};
+      const request = {
+        withCompleteResponse: sinon.stub().returns(response),
+      };
+      const interaction = {
+        given: sinon.stub(),
+        uponReceiving: sinon.stub(),
+        withCompleteRequest: sinon.stub().returns(request),
+      };
+      interaction.given.returns(interaction);
+      interaction.uponReceiving.returns(interaction);
+      const pact = {
+        addInteraction: () =>
+          interaction as unknown as V4UnconfiguredInteraction,
+      } as V4ConsumerPact;
+
+      const result = addInteractionFromOpenApi(pact, document, 'listDogs', 200, {
+        states: [{ description: 'there are dogs', parameters: { count: 2 } }],
+      });
+
+      expect(result).to.eq(response);
+      expect(interaction.given).to.have.been.calledWith('there are dogs', {
+        count: 2,
+      });
+      expect(interaction.uponReceiving).to.have.been.calledWith(
+        'a request for the dogs'
+      );
+      expect(interaction.withCompleteRequest).to.have.been.calledWith({
+        method: 'GET',
+        path: '/dogs',
+      });
+      expect(request.withCompleteResponse).to.have.been.calledWithMatch({
+        status: 200,
+        headers: { 'Content-Type': 'application/json' },
+      });
+    });
+  });
+});
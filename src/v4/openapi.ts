@@ -0,0 +1,41 @@
+import {
+  interactionFromOpenApi,
+  OpenApiDocument,
+  OpenApiInteractionOptions,
+} from '../v3/openapi';
+import { V4InteractionWithResponse } from './http/types';
+import { V4ConsumerPact } from './types';
+
+/**
+ * Adds an interaction for an operation in an OpenAPI 3.x document to a
+ * `PactV4`, generated as by `interactionFromOpenApi`.
+ *
+ * @param pact the pact to add the interaction to
+ * @param document OpenAPI document, or the path to a JSON or YAML file containing it
+ * @param operationId operationId of the operation to generate the interaction for
+ * @param status Status code of the response to use
+ * @param options See {@link OpenApiInteractionOptions}
+ */
+export const addInteractionFromOpenApi = (
+  pact: V4ConsumerPact,
+  document: OpenApiDocument | string,
+  operationId: string,
+  status: number,
+  options: OpenApiInteractionOptions = {}
+): V4InteractionWithResponse => {
+  const interaction = interactionFromOpenApi(
+    document,
+    operationId,
+    status,
+    options
+  );
+
+  return (interaction.states || [])
+    .reduce(
+      (i, state) => i.given(state.description, state.parameters),
+      pact.addInteraction()
+    )
+    .uponReceiving(interaction.uponReceiving)
+    .withCompleteRequest(interaction.withRequest)
+    .withCompleteResponse(interaction.willRespondWith);
+};
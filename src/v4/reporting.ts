@@ -0,0 +1,65 @@
+import { ConsumerPact } from '@pact-foundation/pact-core';
+import {
+  RecordedInteraction,
+  RecordedRequest,
+} from '../reporters/consumer';
+
+// The interactions added to each pact, so their descriptions and states can
+// be reported when the test is executed. Each interaction is looked up by its
+// handle from the core, which every builder in the chain has.
+const interactionsByPact = new WeakMap<ConsumerPact, RecordedInteraction[]>();
+const interactionsByHandle = new WeakMap<object, RecordedInteraction>();
+
+/**
+ * Returns the record of an interaction, recording it against the pact the
+ * first time it is seen
+ */
+export const recordInteraction = (
+  pact: ConsumerPact,
+  handle: object
+): RecordedInteraction => {
+  const existing = interactionsByHandle.get(handle);
+  if (existing) {
+    return existing;
+  }
+
+  const interaction: RecordedInteraction = {
+    description: '',
+    providerStates: [],
+  };
+  interactionsByHandle.set(handle, interaction);
+  interactionsByPact.set(pact, [
+    ...(interactionsByPact.get(pact) ?? []),
+    interaction,
+  ]);
+
+  return interaction;
+};
+
+/**
+ * Records the query, headers or body template of an interaction's request,
+ * which are set by the request builder after the request itself
+ */
+export const recordRequestParts = (
+  handle: object,
+  parts: Pick<RecordedRequest, 'query' | 'headers' | 'body'>
+): void => {
+  const interaction = interactionsByHandle.get(handle);
+  if (interaction?.request) {
+    const { request } = interaction;
+    interaction.request = {
+      ...request,
+      ...parts,
+      query: parts.query
+        ? { ...request.query, ...parts.query }
+        : request.query,
+      headers: parts.headers
+        ? { ...request.headers, ...parts.headers }
+        : request.headers,
+    };
+  }
+};
+
+export const recordedInteractions = (
+  pact: ConsumerPact
+): RecordedInteraction[] => interactionsByPact.get(pact) ?? [];
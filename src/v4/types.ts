@@ -1,9 +1,13 @@
 import { V4UnconfiguredInteraction } from './http/types';
-import { V4UnconfiguredSynchronousMessage } from './message/types';
+import {
+  V4UnconfiguredAsynchronousMessage,
+  V4UnconfiguredSynchronousMessage,
+} from './message/types';
 
 export interface V4ConsumerPact {
   addInteraction(): V4UnconfiguredInteraction;
   addSynchronousInteraction(
     description: string
   ): V4UnconfiguredSynchronousMessage;
+  addAsynchronousMessage(description: string): V4UnconfiguredAsynchronousMessage;
 }